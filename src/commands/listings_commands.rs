@@ -1,7 +1,7 @@
 use super::logging_level_arg;
 use anyhow::Result;
 use crusti_app_helper::{App, AppSettings, ArgMatches, Command, SubCommand};
-use crusti_g2io::{display, generators, linkers, NamedParam};
+use crusti_g2io::{display, generators, linkers, parsers, NamedParam};
 
 macro_rules! listing_cmd {
     ($cmd_ident:ident, $cmd_name:expr, $cmd_description:expr, $listing_fn:expr) => {
@@ -75,6 +75,20 @@ listing_cmd!(
     display::iter_directed_display_engines()
 );
 
+listing_cmd!(
+    ParsersUndirectedCommand,
+    "parsers-undirected",
+    "Lists the available parsers for undirected graphs",
+    parsers::iter_undirected_parsers()
+);
+
+listing_cmd!(
+    ParsersDirectedCommand,
+    "parsers-directed",
+    "Lists the available parsers for directed graphs",
+    parsers::iter_directed_parsers()
+);
+
 fn print_listing<I, S, T>(collection: I)
 where
     I: Iterator<Item = &'static S>,
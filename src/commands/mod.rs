@@ -5,6 +5,7 @@ mod listings_commands;
 pub use listings_commands::{DisplayEnginesDirectedCommand, DisplayEnginesUndirectedCommand};
 pub use listings_commands::{GeneratorsDirectedCommand, GeneratorsUndirectedCommand};
 pub use listings_commands::{LinkersDirectedCommand, LinkersUndirectedCommand};
+pub use listings_commands::{ParsersDirectedCommand, ParsersUndirectedCommand};
 
 use crusti_app_helper::Arg;
 
@@ -1,9 +1,81 @@
 use petgraph::graph::NodeIndex;
 use petgraph::EdgeType;
+use std::fmt::Display;
 
 /// The node label type
 pub type NodeIndexType = usize;
 
+/// A value that can be attached to a node as an attribute.
+#[derive(Clone, Debug, PartialEq)]
+pub enum NodeAttributeValue {
+    /// A positive integer, possibly null (eg. the index of a community)
+    PositiveInteger(usize),
+    /// A list of floating point numbers (eg. coordinates in a space)
+    FloatList(Vec<f64>),
+}
+
+impl Display for NodeAttributeValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NodeAttributeValue::PositiveInteger(n) => write!(f, "{}", n),
+            NodeAttributeValue::FloatList(v) => write!(
+                f,
+                "{}",
+                v.iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<String>>()
+                    .join(",")
+            ),
+        }
+    }
+}
+
+/// The attributes attached to a node.
+///
+/// Attributes are named values, which can be written by display engines that support them (eg. GraphML and DOT).
+/// A node has no attribute unless a generator sets some.
+///
+/// ```
+/// # use crusti_g2io::{Graph, NodeAttributeValue};
+/// use petgraph::Directed;
+///
+/// let mut graph = Graph::<Directed>::default();
+/// graph.new_node();
+/// assert!(graph.node_attributes(0).is_empty());
+/// graph.set_node_attribute(0, "community", NodeAttributeValue::PositiveInteger(1));
+/// assert_eq!(
+///     Some(&NodeAttributeValue::PositiveInteger(1)),
+///     graph.node_attributes(0).get("community")
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NodeAttributes(Vec<(&'static str, NodeAttributeValue)>);
+
+impl NodeAttributes {
+    /// Returns the value of the attribute with the given name, if it is set.
+    pub fn get(&self, name: &str) -> Option<&NodeAttributeValue> {
+        self.0.iter().find(|(n, _)| *n == name).map(|(_, v)| v)
+    }
+
+    /// Sets the value of an attribute, replacing the previous one if the attribute was already set.
+    pub fn set(&mut self, name: &'static str, value: NodeAttributeValue) {
+        match self.0.iter_mut().find(|(n, _)| *n == name) {
+            Some(attr) => attr.1 = value,
+            None => self.0.push((name, value)),
+        }
+    }
+
+    /// Returns `true` if no attribute is set.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator to the attributes, given as couples of names and values, in the order they were first set.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &NodeAttributeValue)> + '_ {
+        self.0.iter().map(|(n, v)| (*n, v))
+    }
+}
+
 /// A directed graph, where nodes are labeled by integers and edges have no labels.
 ///
 /// Graphs can be created empty using the [`default`](Default::default) or with the [`with_capacity`](Self#with_capacity) function.
@@ -36,7 +108,7 @@ pub type NodeIndexType = usize;
 /// }
 /// # path(2);
 /// ```
-pub struct Graph<Ty>(petgraph::Graph<(), (), Ty, NodeIndexType>)
+pub struct Graph<Ty>(petgraph::Graph<NodeAttributes, (), Ty, NodeIndexType>)
 where
     Ty: EdgeType;
 
@@ -45,7 +117,7 @@ where
     Ty: EdgeType,
 {
     fn default() -> Self {
-        Self(petgraph::Graph::<(), (), Ty, NodeIndexType>::default())
+        Self(petgraph::Graph::<NodeAttributes, (), Ty, NodeIndexType>::default())
     }
 }
 
@@ -86,7 +158,7 @@ where
     /// assert_eq!(1, graph.n_nodes());
     /// ```
     pub fn new_node(&mut self) {
-        self.0.add_node(());
+        self.0.add_node(NodeAttributes::default());
     }
 
     /// Returns the number of nodes contained in the graph.
@@ -112,10 +184,10 @@ where
     /// ```
     pub fn new_edge(&mut self, from: NodeIndexType, to: NodeIndexType) {
         (self.n_nodes()..=from).for_each(|_| {
-            self.0.add_node(());
+            self.0.add_node(NodeAttributes::default());
         });
         (self.n_nodes()..=to).for_each(|_| {
-            self.0.add_node(());
+            self.0.add_node(NodeAttributes::default());
         });
         self.0
             .add_edge(NodeIndex::from(from), NodeIndex::from(to), ());
@@ -162,12 +234,35 @@ where
         self.0.remove_edge(index).unwrap();
     }
 
+    /// Sets the value of an attribute of a node, replacing the previous one if the attribute was already set.
+    ///
+    /// # Panics
+    ///
+    /// If the node does not exist, this function panics.
+    pub fn set_node_attribute(
+        &mut self,
+        node: NodeIndexType,
+        name: &'static str,
+        value: NodeAttributeValue,
+    ) {
+        self.0[NodeIndex::from(node)].set(name, value);
+    }
+
+    /// Returns the attributes of a node.
+    ///
+    /// # Panics
+    ///
+    /// If the node does not exist, this function panics.
+    pub fn node_attributes(&self, node: NodeIndexType) -> &NodeAttributes {
+        &self.0[NodeIndex::from(node)]
+    }
+
     pub(crate) fn append_graph(&mut self, g: &Graph<Ty>) {
         let self_n_nodes = self.n_nodes();
         let g_n_nodes = g.n_nodes();
         self.0.reserve_nodes(g_n_nodes);
-        (0..g_n_nodes).for_each(|_| {
-            self.0.add_node(());
+        g.0.raw_nodes().iter().for_each(|n| {
+            self.0.add_node(n.weight.clone());
         });
         self.0.reserve_edges(g.n_edges());
         for edge in g.0.raw_edges() {
@@ -178,7 +273,7 @@ where
         }
     }
 
-    pub(crate) fn petgraph(&self) -> &petgraph::Graph<(), (), Ty, NodeIndexType> {
+    pub(crate) fn petgraph(&self) -> &petgraph::Graph<NodeAttributes, (), Ty, NodeIndexType> {
         &self.0
     }
 }
@@ -186,12 +281,14 @@ where
 /// A structure used to store an inner graph.
 ///
 /// Its main purpose is to associate an index to a graph, allowing linkers to cache data.
+/// It also associates a seed to the graph, allowing linkers to make random choices that do not depend on the pair of graphs being linked.
 pub struct InnerGraph<'a, Ty>
 where
     Ty: EdgeType,
 {
     index: usize,
     graph: &'a Graph<Ty>,
+    seed: u64,
 }
 
 impl<Ty> InnerGraph<'_, Ty>
@@ -207,6 +304,14 @@ where
     pub fn graph(&self) -> &Graph<Ty> {
         self.graph
     }
+
+    /// Returns the seed associated with the inner graph.
+    ///
+    /// During a generation process, this seed is drawn from the main PRNG, and is the same for all the links involving the inner graph.
+    /// Inner graphs built from a pair made of an index and a graph have a null seed.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
 }
 
 impl<'a, Ty> From<(usize, &'a Graph<Ty>)> for InnerGraph<'a, Ty>
@@ -217,6 +322,20 @@ where
         Self {
             index: t.0,
             graph: t.1,
+            seed: 0,
+        }
+    }
+}
+
+impl<'a, Ty> From<(usize, &'a Graph<Ty>, u64)> for InnerGraph<'a, Ty>
+where
+    Ty: EdgeType,
+{
+    fn from(t: (usize, &'a Graph<Ty>, u64)) -> Self {
+        Self {
+            index: t.0,
+            graph: t.1,
+            seed: t.2,
         }
     }
 }
@@ -226,7 +345,7 @@ where
     Ty: EdgeType,
 {
     fn from(g: petgraph::Graph<(), (), Ty, NodeIndexType>) -> Self {
-        Self(g)
+        Self(g.map(|_, _| NodeAttributes::default(), |_, _| ()))
     }
 }
 
@@ -250,7 +369,7 @@ mod tests {
 
     #[test]
     fn test_iter_edges() {
-        let g: Graph<Directed> = Graph(petgraph::Graph::from_edges(&[(0, 1), (0, 0)]));
+        let g: Graph<Directed> = Graph(petgraph::Graph::from_edges([(0, 1), (0, 0)]));
         assert_eq!(
             vec![(0, 1), (0, 0)],
             g.iter_edges()
@@ -260,14 +379,14 @@ mod tests {
 
     #[test]
     fn test_append_graph() {
-        let mut g0: Graph<Directed> = Graph(petgraph::Graph::from_edges(&[(0, 1)]));
+        let mut g0: Graph<Directed> = Graph(petgraph::Graph::from_edges([(0, 1)]));
         assert_eq!(2, g0.n_nodes());
         assert_eq!(
             vec![(0, 1)],
             g0.iter_edges()
                 .collect::<Vec<(NodeIndexType, NodeIndexType)>>()
         );
-        let g1 = Graph(petgraph::Graph::from_edges(&[(1, 0)]));
+        let g1 = Graph(petgraph::Graph::from_edges([(1, 0)]));
         g0.append_graph(&g1);
         assert_eq!(4, g0.n_nodes());
         assert_eq!(
@@ -275,7 +394,7 @@ mod tests {
             g0.iter_edges()
                 .collect::<Vec<(NodeIndexType, NodeIndexType)>>()
         );
-        let g2 = Graph(petgraph::Graph::from_edges(&[(0, 1), (1, 0)]));
+        let g2 = Graph(petgraph::Graph::from_edges([(0, 1), (1, 0)]));
         g0.append_graph(&g2);
         assert_eq!(6, g0.n_nodes());
         assert_eq!(
@@ -284,4 +403,40 @@ mod tests {
                 .collect::<Vec<(NodeIndexType, NodeIndexType)>>()
         );
     }
+
+    #[test]
+    fn test_append_graph_keeps_attributes() {
+        let mut g0: Graph<Directed> = Graph::default();
+        g0.new_node();
+        let mut g1 = Graph::default();
+        g1.new_edge(0, 1);
+        g1.set_node_attribute(1, "community", NodeAttributeValue::PositiveInteger(2));
+        g0.append_graph(&g1);
+        assert!(g0.node_attributes(0).is_empty());
+        assert!(g0.node_attributes(1).is_empty());
+        assert_eq!(
+            Some(&NodeAttributeValue::PositiveInteger(2)),
+            g0.node_attributes(2).get("community")
+        );
+    }
+
+    #[test]
+    fn test_set_node_attribute_replaces_value() {
+        let mut g: Graph<Directed> = Graph::default();
+        g.new_node();
+        g.set_node_attribute(0, "community", NodeAttributeValue::PositiveInteger(1));
+        g.set_node_attribute(0, "level", NodeAttributeValue::PositiveInteger(0));
+        g.set_node_attribute(0, "community", NodeAttributeValue::PositiveInteger(2));
+        assert_eq!(
+            vec![
+                ("community", &NodeAttributeValue::PositiveInteger(2)),
+                ("level", &NodeAttributeValue::PositiveInteger(0))
+            ],
+            g.node_attributes(0).iter().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            "2",
+            g.node_attributes(0).get("community").unwrap().to_string()
+        );
+    }
 }
@@ -168,6 +168,8 @@ impl InnerOuterGenerator {
     {
         let raw_edges = outer_graph.petgraph().raw_edges();
         let seeds: Vec<u64> = rng.sample_iter(Standard).take(raw_edges.len()).collect();
+        // drawn after the seeds of the edges, so that the links of linkers which ignore them do not change
+        let inner_seeds: Vec<u64> = rng.sample_iter(Standard).take(inner_graphs.len()).collect();
         let all_global_edges = (0..raw_edges.len())
             .into_par_iter()
             .map(|i| {
@@ -178,8 +180,18 @@ impl InnerOuterGenerator {
                     petgraph_edge.target().index(),
                 );
                 let inter_attacks = (linker)(
-                    (outer_edge.0, &inner_graphs[outer_edge.0]).into(),
-                    (outer_edge.1, &inner_graphs[outer_edge.1]).into(),
+                    (
+                        outer_edge.0,
+                        &inner_graphs[outer_edge.0],
+                        inner_seeds[outer_edge.0],
+                    )
+                        .into(),
+                    (
+                        outer_edge.1,
+                        &inner_graphs[outer_edge.1],
+                        inner_seeds[outer_edge.1],
+                    )
+                        .into(),
                     &mut rng,
                 );
                 inter_attacks
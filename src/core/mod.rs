@@ -2,6 +2,8 @@ mod graph;
 pub use graph::Graph;
 pub use graph::InnerGraph;
 pub use graph::InterGraphEdge;
+pub use graph::NodeAttributeValue;
+pub use graph::NodeAttributes;
 pub use graph::NodeIndexType;
 
 mod inner_outer_generator;
@@ -34,6 +34,14 @@ pub enum ParameterType {
     PositiveInteger,
     /// A floating point number between 0 and 1 (both allowed)
     Probability,
+    /// A finite positive floating point number, possibly null
+    PositiveFloat,
+    /// A non-empty list of positive integers (possibly null), separated by colons
+    PositiveIntegerList,
+    /// A non-empty list of probabilities, separated by colons
+    ProbabilityList,
+    /// A non-empty string, which cannot contain commas
+    String,
 }
 
 impl ParameterType {
@@ -52,6 +60,40 @@ impl ParameterType {
                     ParameterValue::Probability(p)
                 }
             }
+            ParameterType::PositiveFloat => {
+                let context = "while translating a string into a positive floating point number";
+                let f: f64 = str::parse(param).context(context)?;
+                if !f.is_finite() || f < 0. {
+                    return Err(anyhow!("value must be a finite positive number")).context(context);
+                } else {
+                    ParameterValue::PositiveFloat(f)
+                }
+            }
+            ParameterType::PositiveIntegerList => ParameterValue::PositiveIntegerList(
+                param
+                    .split(':')
+                    .map(str::parse::<usize>)
+                    .collect::<Result<Vec<usize>, _>>()
+                    .context("while translating a string into a list of positive integers")?,
+            ),
+            ParameterType::ProbabilityList => {
+                let context = "while translating a string into a list of probabilities";
+                let probabilities = param
+                    .split(':')
+                    .map(str::parse::<f64>)
+                    .collect::<Result<Vec<f64>, _>>()
+                    .context(context)?;
+                if probabilities.iter().any(|p| !(0. ..=1.).contains(p)) {
+                    return Err(anyhow!("probabilities must be between 0 and 1")).context(context);
+                }
+                ParameterValue::ProbabilityList(probabilities)
+            }
+            ParameterType::String => {
+                if param.is_empty() {
+                    return Err(anyhow!("string must not be empty"));
+                }
+                ParameterValue::String(param.to_string())
+            }
         })
     }
 }
@@ -66,6 +108,14 @@ pub enum ParameterValue {
     PositiveInteger(usize),
     /// A floating point number between 0 and 1 (both allowed)
     Probability(f64),
+    /// A finite positive floating point number, possibly null
+    PositiveFloat(f64),
+    /// A non-empty list of positive integers (possibly null)
+    PositiveIntegerList(Vec<usize>),
+    /// A non-empty list of probabilities
+    ProbabilityList(Vec<f64>),
+    /// A non-empty string
+    String(String),
 }
 
 impl ParameterValue {
@@ -81,14 +131,50 @@ impl ParameterValue {
         }
     }
 
-    /// Unwraps a parameter value which value can be seen as a probability.
+    /// Unwraps a parameter value which value can be seen as a floating point number (a probability or a positive float).
     ///
     /// # Panics
     ///
-    /// This function panics if the value can not be seen as a probability.
+    /// This function panics if the value can not be seen as a floating point number.
     pub fn unwrap_f64(&self) -> f64 {
         match self {
-            ParameterValue::Probability(f) => *f,
+            ParameterValue::Probability(f) | ParameterValue::PositiveFloat(f) => *f,
+            _ => panic!(),
+        }
+    }
+
+    /// Unwraps a parameter value which value can be seen as a list of positive integers.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the value can not be seen as a list of positive integers.
+    pub fn unwrap_usize_list(&self) -> &[usize] {
+        match self {
+            ParameterValue::PositiveIntegerList(v) => v,
+            _ => panic!(),
+        }
+    }
+
+    /// Unwraps a parameter value which value can be seen as a list of floating point numbers.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the value can not be seen as a list of floating point numbers.
+    pub fn unwrap_f64_list(&self) -> &[f64] {
+        match self {
+            ParameterValue::ProbabilityList(v) => v,
+            _ => panic!(),
+        }
+    }
+
+    /// Unwraps a parameter value which value can be seen as a string.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the value can not be seen as a string.
+    pub fn unwrap_str(&self) -> &str {
+        match self {
+            ParameterValue::String(s) => s,
             _ => panic!(),
         }
     }
@@ -162,4 +248,82 @@ mod tests {
         assert!(parser.parse("1.5").is_err());
         assert!(parser.parse("a").is_err());
     }
+
+    #[test]
+    pub fn test_positive_float_ok() {
+        let parser = ParameterParser::new(vec![ParameterType::PositiveFloat]);
+        assert_eq!(
+            vec![ParameterValue::PositiveFloat(2.5)],
+            parser.parse("2.5").unwrap()
+        );
+        assert_eq!(
+            vec![ParameterValue::PositiveFloat(0.)],
+            parser.parse("0").unwrap()
+        );
+    }
+
+    #[test]
+    pub fn test_positive_float_not_ok() {
+        let parser = ParameterParser::new(vec![ParameterType::PositiveFloat]);
+        assert!(parser.parse("-0.5").is_err());
+        assert!(parser.parse("inf").is_err());
+        assert!(parser.parse("NaN").is_err());
+        assert!(parser.parse("a").is_err());
+    }
+
+    #[test]
+    pub fn test_positive_integer_list_ok() {
+        let parser = ParameterParser::new(vec![ParameterType::PositiveIntegerList]);
+        assert_eq!(
+            vec![ParameterValue::PositiveIntegerList(vec![1])],
+            parser.parse("1").unwrap()
+        );
+        assert_eq!(
+            vec![ParameterValue::PositiveIntegerList(vec![1, 0, 2])],
+            parser.parse("1:0:2").unwrap()
+        );
+    }
+
+    #[test]
+    pub fn test_positive_integer_list_not_ok() {
+        let parser = ParameterParser::new(vec![ParameterType::PositiveIntegerList]);
+        assert!(parser.parse("1:").is_err());
+        assert!(parser.parse("1:-1").is_err());
+        assert!(parser.parse("1:a").is_err());
+    }
+
+    #[test]
+    pub fn test_probability_list_ok() {
+        let parser = ParameterParser::new(vec![ParameterType::ProbabilityList]);
+        assert_eq!(
+            vec![ParameterValue::ProbabilityList(vec![0.5, 0., 1.])],
+            parser.parse(".5:0:1").unwrap()
+        );
+    }
+
+    #[test]
+    pub fn test_probability_list_not_ok() {
+        let parser = ParameterParser::new(vec![ParameterType::ProbabilityList]);
+        assert!(parser.parse("0.5:").is_err());
+        assert!(parser.parse("0.5:1.5").is_err());
+        assert!(parser.parse("0.5:a").is_err());
+    }
+
+    #[test]
+    pub fn test_string_ok() {
+        let parser = ParameterParser::new(vec![ParameterType::String, ParameterType::String]);
+        assert_eq!(
+            vec![
+                ParameterValue::String("foo/bar.txt".to_string()),
+                ParameterValue::String("a:b".to_string())
+            ],
+            parser.parse("foo/bar.txt,a:b").unwrap()
+        );
+    }
+
+    #[test]
+    pub fn test_string_not_ok() {
+        let parser = ParameterParser::new(vec![ParameterType::String, ParameterType::String]);
+        assert!(parser.parse("foo,").is_err());
+    }
 }
@@ -18,7 +18,10 @@ where
     }
 
     fn description(&self) -> Vec<&'static str> {
-        vec!["Output a graph using the Graphviz DOT format."]
+        vec![
+            "Output a graph using the Graphviz DOT format.",
+            "Node attributes (eg. communities) are exported as DOT attributes.",
+        ]
     }
 
     fn expected_parameter_types(&self) -> Vec<ParameterType> {
@@ -27,8 +30,17 @@ where
 
     fn try_with_params(&self, _parameter_values: Vec<ParameterValue>) -> Result<BoxedDisplay<Ty>> {
         Ok(Box::new(|f, g| {
-            let dot_display =
-                Dot::with_config(g.petgraph(), &[Config::NodeIndexLabel, Config::EdgeNoLabel]);
+            let dot_display = Dot::with_attr_getters(
+                g.petgraph(),
+                &[Config::NodeIndexLabel, Config::EdgeNoLabel],
+                &|_, _| String::new(),
+                &|_, (_, attrs)| {
+                    attrs
+                        .iter()
+                        .map(|(name, value)| format!(r#"{} = "{}" "#, name, value))
+                        .collect()
+                },
+            );
             std::fmt::Debug::fmt(&dot_display, f)
         }))
     }
@@ -3,7 +3,7 @@ use crate::{NamedParam, ParameterType, ParameterValue};
 use anyhow::Result;
 use petgraph::EdgeType;
 use petgraph_graphml::GraphMl;
-use std::fmt::Display;
+use std::{borrow::Cow, fmt::Display};
 
 #[derive(Default)]
 pub struct GraphMLGraphDisplay;
@@ -17,7 +17,10 @@ where
     }
 
     fn description(&self) -> Vec<&'static str> {
-        vec!["Output a graph using the GraphML."]
+        vec![
+            "Output a graph using the GraphML.",
+            "Node attributes (eg. communities) are exported as GraphML data.",
+        ]
     }
 
     fn expected_parameter_types(&self) -> Vec<ParameterType> {
@@ -26,7 +29,14 @@ where
 
     fn try_with_params(&self, _parameter_values: Vec<ParameterValue>) -> Result<BoxedDisplay<Ty>> {
         Ok(Box::new(|f, g| {
-            let graphml = GraphMl::new(g.petgraph()).pretty_print(true);
+            let graphml = GraphMl::new(g.petgraph())
+                .pretty_print(true)
+                .export_node_weights(Box::new(|attrs| {
+                    attrs
+                        .iter()
+                        .map(|(name, value)| (Cow::Borrowed(name), Cow::Owned(value.to_string())))
+                        .collect()
+                }));
             graphml.fmt(f)
         }))
     }
@@ -41,18 +41,18 @@ where
 
 lazy_static! {
     pub(crate) static ref DISPLAY_DIRECTED: [Box<dyn GraphDisplay<Directed> + Sync>; 4] = [
-        Box::new(AspartixGraphDisplay::default()),
-        Box::new(DotGraphDisplay::default()),
-        Box::new(GraphMLGraphDisplay::default()),
-        Box::new(ICCMADimacsGraphDisplay::default())
+        Box::new(AspartixGraphDisplay),
+        Box::new(DotGraphDisplay),
+        Box::new(GraphMLGraphDisplay),
+        Box::new(ICCMADimacsGraphDisplay)
     ];
 }
 
 lazy_static! {
     pub(crate) static ref DISPLAY_UNDIRECTED: [Box<dyn GraphDisplay<Undirected> + Sync>; 3] = [
-        Box::new(DotGraphDisplay::default()),
-        Box::new(GraphMLGraphDisplay::default()),
-        Box::new(ICCMADimacsGraphDisplay::default())
+        Box::new(DotGraphDisplay),
+        Box::new(GraphMLGraphDisplay),
+        Box::new(ICCMADimacsGraphDisplay)
     ];
 }
 
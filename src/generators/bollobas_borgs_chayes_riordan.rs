@@ -0,0 +1,239 @@
+use super::{BoxedGenerator, GeneratorFactory};
+use crate::{Graph, NamedParam, NodeIndexType, ParameterType, ParameterValue};
+use anyhow::{anyhow, Context, Result};
+use petgraph::Directed;
+use rand::Rng;
+use std::collections::HashSet;
+
+/// A factory used to build generators for directed scale-free graphs following the model of [Bollobás, Borgs, Chayes and Riordan](https://dl.acm.org/doi/10.5555/644108.644133).
+///
+/// The process starts with a directed cycle of 3 nodes, and repeats the following steps until the graph has `n` nodes:
+///   - with probability `alpha`, a new node is added with an edge to an existing node `w`;
+///   - with probability `beta`, an edge is added from an existing node `v` to an existing node `w`;
+///   - with probability `gamma`, a new node is added with an edge from an existing node `v`.
+///
+/// The node `v` is chosen with a probability proportional to its out-degree plus `delta_out`,
+/// while `w` is chosen with a probability proportional to its in-degree plus `delta_in`.
+/// The in-degrees and out-degrees of the resulting graph follow independent power laws.
+/// Steps that would create a self-loop or a multi-edge are discarded.
+///
+/// This generator is only available for directed graphs.
+///
+/// Such factories can be created by passing `bbcr/n,alpha,beta,gamma,delta_in,delta_out` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str) where
+///   - `n` is the size of graph to produce;
+///   - `alpha`, `beta` and `gamma` are the probabilities of the three kinds of steps, which sum must be 1;
+///   - `delta_in` and `delta_out` are the biases added to the in-degrees and the out-degrees.
+///
+/// Parameter `n` must be at least 3, and `alpha + gamma` must be higher than zero.
+#[derive(Default)]
+pub struct BollobasBorgsChayesRiordanGeneratorFactory;
+
+impl<R> NamedParam<BoxedGenerator<Directed, R>> for BollobasBorgsChayesRiordanGeneratorFactory
+where
+    R: Rng,
+{
+    fn name(&self) -> &'static str {
+        "bbcr"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator following the directed preferential attachment model of Bollobás, Borgs, Chayes and Riordan.",
+            "First parameter gives the number of nodes; the three next ones give the probabilities to add a node with an outgoing edge, an edge between existing nodes, and a node with an incoming edge.",
+            "The two last parameters give the biases added to the in-degrees and the out-degrees.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![
+            ParameterType::PositiveInteger,
+            ParameterType::Probability,
+            ParameterType::Probability,
+            ParameterType::Probability,
+            ParameterType::PositiveFloat,
+            ParameterType::PositiveFloat,
+        ]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Directed, R>> {
+        let context = "while building a Bollobás-Borgs-Chayes-Riordan generator";
+        let n = parameter_values[0].unwrap_usize();
+        let alpha = parameter_values[1].unwrap_f64();
+        let beta = parameter_values[2].unwrap_f64();
+        let gamma = parameter_values[3].unwrap_f64();
+        let delta_in = parameter_values[4].unwrap_f64();
+        let delta_out = parameter_values[5].unwrap_f64();
+        if n < 3 {
+            return Err(anyhow!(r#"first parameter ("n") must be at least 3"#)).context(context);
+        }
+        if (alpha + beta + gamma - 1.).abs() > 1e-6 {
+            return Err(anyhow!(
+                r#"the sum of the probabilities ("alpha+beta+gamma") must be equal to 1"#
+            ))
+            .context(context);
+        }
+        if alpha + gamma == 0. {
+            return Err(anyhow!(
+                r#"the probability to add a node ("alpha+gamma") must be higher than 0"#
+            ))
+            .context(context);
+        }
+        Ok(Box::new(move |r| {
+            build_graph(n, alpha, beta, delta_in, delta_out, r)
+        }))
+    }
+}
+
+impl<R> GeneratorFactory<Directed, R> for BollobasBorgsChayesRiordanGeneratorFactory where R: Rng {}
+
+fn build_graph<R>(
+    n: usize,
+    alpha: f64,
+    beta: f64,
+    delta_in: f64,
+    delta_out: f64,
+    r: &mut R,
+) -> Graph<Directed>
+where
+    R: Rng,
+{
+    let mut sources: Vec<NodeIndexType> = vec![0, 1, 2];
+    let mut targets: Vec<NodeIndexType> = vec![1, 2, 0];
+    let mut edges = sources
+        .iter()
+        .copied()
+        .zip(targets.iter().copied())
+        .collect::<HashSet<(NodeIndexType, NodeIndexType)>>();
+    let mut n_nodes = 3;
+    while n_nodes < n {
+        let x = r.gen::<f64>();
+        let (v, w) = if x < alpha {
+            let w = choose_node(&targets, delta_in, n_nodes, r);
+            n_nodes += 1;
+            (n_nodes - 1, w)
+        } else if x < alpha + beta {
+            let v = choose_node(&sources, delta_out, n_nodes, r);
+            let w = choose_node(&targets, delta_in, n_nodes, r);
+            if v == w || edges.contains(&(v, w)) {
+                continue;
+            }
+            (v, w)
+        } else {
+            let v = choose_node(&sources, delta_out, n_nodes, r);
+            n_nodes += 1;
+            (v, n_nodes - 1)
+        };
+        edges.insert((v, w));
+        sources.push(v);
+        targets.push(w);
+    }
+    let mut g = Graph::with_capacity(n, sources.len());
+    (0..n).for_each(|_| g.new_node());
+    sources
+        .into_iter()
+        .zip(targets)
+        .for_each(|(v, w)| g.new_edge(v, w));
+    g
+}
+
+/// Chooses a node with a probability proportional to the number of its occurrences in the stubs plus `delta`.
+fn choose_node<R>(stubs: &[NodeIndexType], delta: f64, n_nodes: usize, r: &mut R) -> NodeIndexType
+where
+    R: Rng,
+{
+    let total_weight = stubs.len() as f64 + delta * n_nodes as f64;
+    if r.gen::<f64>() * total_weight < stubs.len() as f64 {
+        stubs[r.gen_range(0..stubs.len())]
+    } else {
+        r.gen_range(0..n_nodes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::ThreadRng, SeedableRng};
+    use rand_pcg::Pcg32;
+
+    fn params(n: usize, probabilities: [f64; 3], deltas: [f64; 2]) -> Vec<ParameterValue> {
+        let mut params = vec![ParameterValue::PositiveInteger(n)];
+        params.append(
+            &mut probabilities
+                .iter()
+                .map(|p| ParameterValue::Probability(*p))
+                .collect(),
+        );
+        params.append(
+            &mut deltas
+                .iter()
+                .map(|d| ParameterValue::PositiveFloat(*d))
+                .collect(),
+        );
+        params
+    }
+
+    #[test]
+    fn test_n_too_low() {
+        assert!(
+            (BollobasBorgsChayesRiordanGeneratorFactory.try_with_params(params(
+                2,
+                [0.4, 0.2, 0.4],
+                [0.2, 0.]
+            )) as Result<BoxedGenerator<Directed, ThreadRng>>)
+                .is_err()
+        )
+    }
+
+    #[test]
+    fn test_wrong_sum() {
+        assert!(
+            (BollobasBorgsChayesRiordanGeneratorFactory.try_with_params(params(
+                10,
+                [0.4, 0.4, 0.4],
+                [0.2, 0.]
+            )) as Result<BoxedGenerator<Directed, ThreadRng>>)
+                .is_err()
+        )
+    }
+
+    #[test]
+    fn test_no_node_addition() {
+        assert!(
+            (BollobasBorgsChayesRiordanGeneratorFactory.try_with_params(params(
+                10,
+                [0., 1., 0.],
+                [0.2, 0.]
+            )) as Result<BoxedGenerator<Directed, ThreadRng>>)
+                .is_err()
+        )
+    }
+
+    #[test]
+    fn test_only_alpha() {
+        let g: Graph<Directed> = BollobasBorgsChayesRiordanGeneratorFactory
+            .try_with_params(params(100, [1., 0., 0.], [0., 0.]))
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(100, g.n_nodes());
+        assert_eq!(100, g.n_edges());
+        let mut out_degrees = vec![0; 100];
+        g.iter_edges().for_each(|(v, _)| out_degrees[v] += 1);
+        assert_eq!(vec![1; 100], out_degrees);
+    }
+
+    #[test]
+    fn test_simple_graph() {
+        let g: Graph<Directed> = BollobasBorgsChayesRiordanGeneratorFactory
+            .try_with_params(params(1000, [0.41, 0.54, 0.05], [0.2, 0.]))
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(1000, g.n_nodes());
+        assert!(g.n_edges() > 1000);
+        let mut edges = HashSet::new();
+        g.iter_edges().for_each(|(v, w)| {
+            assert_ne!(v, w);
+            assert!(edges.insert((v, w)));
+        });
+    }
+}
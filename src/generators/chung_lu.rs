@@ -0,0 +1,335 @@
+use super::{
+    degree_sequence::{self, DegreeDistribution},
+    BoxedGenerator, GeneratorFactory,
+};
+use crate::{Graph, NamedParam, NodeIndexType, ParameterType, ParameterValue};
+use anyhow::{anyhow, Context, Result};
+use petgraph::EdgeType;
+use rand::Rng;
+
+/// A factory used to build generators for graphs following the [Chung-Lu model](https://doi.org/10.1007/PL00012580), with expected degrees sampled from a distribution.
+///
+/// Given a sequence of expected degrees (weights) `w`, each pair of distinct nodes `(u, v)` is linked with probability `min(1, w_u * w_v / S)`, where `S` is the sum of the weights.
+/// Edges are sampled with the algorithm of Miller and Hagberg, which time complexity is linear in the number of nodes and edges.
+///
+/// Distributions are given by strings, which are one of:
+///   - `powerlaw:e:min:max` for a power law with exponent `e` and values between `min` and `max`;
+///   - `poisson:l` for a Poisson distribution with mean `l`;
+///   - `uniform:min:max` for a uniform distribution of the integers between `min` and `max` (both included).
+///
+/// In undirected graphs, such factories can be created by passing `chung_lu/n,d` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str) where
+///   - `n` is the size of graph to produce;
+///   - `d` is the distribution of the expected degrees.
+///
+/// In directed graphs, such factories can be created by passing `chung_lu/n,dout,din` where `dout` and `din` are the distributions of the expected outgoing and incoming degrees.
+/// An edge from `u` to `v` appears with probability `min(1, wout_u * win_v / S)`; incoming weights are scaled so that their sum is equal to the sum `S` of the outgoing ones.
+#[derive(Default)]
+pub struct ChungLuGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for ChungLuGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "chung_lu"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        if Ty::is_directed() {
+            vec![
+                "A generator following the Chung-Lu model, with expected degrees sampled from distributions.",
+                "First parameter gives the number of nodes, the second and the third ones the distributions of expected outgoing and incoming degrees.",
+                r#"Distributions are one of "powerlaw:e:min:max", "poisson:l" and "uniform:min:max"."#,
+            ]
+        } else {
+            vec![
+                "A generator following the Chung-Lu model, with expected degrees sampled from a distribution.",
+                "First parameter gives the number of nodes, the second one the distribution of expected degrees.",
+                r#"Distributions are one of "powerlaw:e:min:max", "poisson:l" and "uniform:min:max"."#,
+            ]
+        }
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        if Ty::is_directed() {
+            vec![
+                ParameterType::PositiveInteger,
+                ParameterType::String,
+                ParameterType::String,
+            ]
+        } else {
+            vec![ParameterType::PositiveInteger, ParameterType::String]
+        }
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let n = parameter_values[0].unwrap_usize();
+        let distributions = parameter_values[1..]
+            .iter()
+            .map(|p| p.unwrap_str().parse::<DegreeDistribution>())
+            .collect::<Result<Vec<DegreeDistribution>>>()
+            .context("while building a Chung-Lu generator")?;
+        Ok(Box::new(move |r| {
+            let mut sample_weights = |d: &DegreeDistribution| {
+                d.sample_sequence(n, r)
+                    .into_iter()
+                    .map(|w| w as f64)
+                    .collect::<Vec<f64>>()
+            };
+            if Ty::is_directed() {
+                let out_weights = sample_weights(&distributions[0]);
+                let in_weights = sample_weights(&distributions[1]);
+                build_graph(&out_weights, &in_weights, r)
+            } else {
+                let weights = sample_weights(&distributions[0]);
+                build_graph(&weights, &weights, r)
+            }
+        }))
+    }
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for ChungLuGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+/// A factory used to build generators for graphs following the [Chung-Lu model](https://doi.org/10.1007/PL00012580), with expected degrees read from a file.
+///
+/// See [`ChungLuGeneratorFactory`] for a description of the model.
+///
+/// Such factories can be created by passing `chung_lu_file/path` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str),
+/// where `path` is the path to the file containing the expected degrees.
+///
+/// The file is read once, when the factory is built.
+/// Empty lines and lines beginning with `#` are ignored.
+/// For undirected graphs, each other line must contain the expected degree of a node.
+/// For directed graphs, each other line must contain the expected outgoing and incoming degrees of a node, separated by whitespaces.
+/// Expected degrees are finite positive floating point numbers.
+#[derive(Default)]
+pub struct ChungLuFileGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for ChungLuFileGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "chung_lu_file"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        if Ty::is_directed() {
+            vec![
+                "A generator following the Chung-Lu model, with expected degrees read from a file.",
+                "First parameter is the path to a file where each line gives the expected outgoing and incoming degrees of a node.",
+            ]
+        } else {
+            vec![
+                "A generator following the Chung-Lu model, with expected degrees read from a file.",
+                "First parameter is the path to a file where each line gives the expected degree of a node.",
+            ]
+        }
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![ParameterType::String]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let context = "while building a Chung-Lu generator";
+        let path = parameter_values[0].unwrap_str();
+        let n_columns = if Ty::is_directed() { 2 } else { 1 };
+        let mut sequences =
+            degree_sequence::read_sequences::<f64>(path, n_columns).context(context)?;
+        if sequences
+            .iter()
+            .flatten()
+            .any(|w| !w.is_finite() || *w < 0.)
+        {
+            return Err(anyhow!("expected degrees must be finite positive numbers"))
+                .context(context);
+        }
+        let in_weights = sequences.pop().unwrap();
+        let out_weights = sequences.pop().unwrap_or_else(|| in_weights.clone());
+        Ok(Box::new(move |r| build_graph(&out_weights, &in_weights, r)))
+    }
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for ChungLuFileGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+fn build_graph<Ty, R>(out_weights: &[f64], in_weights: &[f64], r: &mut R) -> Graph<Ty>
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    let n = out_weights.len();
+    let mut g = Graph::with_capacity(n, 0);
+    (0..n).for_each(|_| g.new_node());
+    let total = out_weights.iter().sum::<f64>();
+    let total_in = in_weights.iter().sum::<f64>();
+    if total == 0. || total_in == 0. {
+        return g;
+    }
+    let in_weights = in_weights
+        .iter()
+        .map(|w| w * total / total_in)
+        .collect::<Vec<f64>>();
+    let mut targets = (0..n).collect::<Vec<NodeIndexType>>();
+    targets.sort_by(|i, j| in_weights[*j].total_cmp(&in_weights[*i]));
+    if Ty::is_directed() {
+        (0..n).for_each(|u| {
+            sample_neighbors(u, out_weights[u], &targets, &in_weights, total, r)
+                .into_iter()
+                .for_each(|v| g.new_edge(u, v))
+        });
+    } else {
+        targets.iter().enumerate().for_each(|(k, u)| {
+            sample_neighbors(
+                *u,
+                out_weights[*u],
+                &targets[k + 1..],
+                &in_weights,
+                total,
+                r,
+            )
+            .into_iter()
+            .for_each(|v| g.new_edge(*u, v))
+        });
+    }
+    g
+}
+
+/// Samples the neighbors of a node among candidates sorted by decreasing weights.
+///
+/// Instead of considering each candidate, the number of candidates to skip is sampled from a geometric distribution.
+fn sample_neighbors<R>(
+    u: NodeIndexType,
+    weight: f64,
+    candidates: &[NodeIndexType],
+    weights: &[f64],
+    total: f64,
+    r: &mut R,
+) -> Vec<NodeIndexType>
+where
+    R: Rng,
+{
+    let probability = |v: NodeIndexType| (weight * weights[v] / total).min(1.);
+    let mut neighbors = vec![];
+    let mut k = 0;
+    let mut p = match candidates.first() {
+        Some(v) => probability(*v),
+        None => return neighbors,
+    };
+    while k < candidates.len() && p > 0. {
+        if p < 1. {
+            let x = 1. - r.gen::<f64>();
+            k = k.saturating_add((x.ln() / (1. - p).ln()).floor() as usize);
+        }
+        if k < candidates.len() {
+            let v = candidates[k];
+            let q = probability(v);
+            if r.gen::<f64>() < q / p && v != u {
+                neighbors.push(v);
+            }
+            p = q;
+            k += 1;
+        }
+    }
+    neighbors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{power_law::PowerLaw, write_tmp_file};
+    use petgraph::{Directed, Undirected};
+    use rand::{rngs::ThreadRng, SeedableRng};
+    use rand_pcg::Pcg32;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_wrong_distribution() {
+        assert!((ChungLuGeneratorFactory.try_with_params(vec![
+            ParameterValue::PositiveInteger(10),
+            ParameterValue::String("foo:1".to_string()),
+        ]) as Result<BoxedGenerator<Undirected, ThreadRng>>)
+            .is_err())
+    }
+
+    #[test]
+    fn test_complete() {
+        let g: Graph<Undirected> = ChungLuGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(10),
+                ParameterValue::String("uniform:20:20".to_string()),
+            ])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(10, g.n_nodes());
+        assert_eq!(45, g.n_edges());
+    }
+
+    #[test]
+    fn test_expected_degrees() {
+        let g: Graph<Undirected> = ChungLuGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(2000),
+                ParameterValue::String("powerlaw:2.5:5:50".to_string()),
+            ])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(2000, g.n_nodes());
+        let mean_degree = 2. * g.n_edges() as f64 / 2000.;
+        let expected_mean = PowerLaw::new(5., 50., 2.5).mean();
+        assert!((mean_degree - expected_mean).abs() < 1.);
+        let mut edges = HashSet::new();
+        g.iter_edges().for_each(|(i, j)| {
+            assert_ne!(i, j);
+            assert!(edges.insert((i.min(j), i.max(j))));
+        });
+    }
+
+    #[test]
+    fn test_directed() {
+        let g: Graph<Directed> = ChungLuGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(1000),
+                ParameterValue::String("uniform:4:4".to_string()),
+                ParameterValue::String("poisson:4".to_string()),
+            ])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(1000, g.n_nodes());
+        assert!((g.n_edges() as f64 / 1000. - 4.).abs() < 0.5);
+        g.iter_edges().for_each(|(i, j)| assert_ne!(i, j));
+    }
+
+    #[test]
+    fn test_file() {
+        let path = write_tmp_file("chung_lu.txt", "# out in\n3 0\n0 3\n0 3\n0 3\n");
+        let g: Graph<Directed> = ChungLuFileGeneratorFactory
+            .try_with_params(vec![ParameterValue::String(path.clone())])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        let mut edges = g
+            .iter_edges()
+            .collect::<Vec<(NodeIndexType, NodeIndexType)>>();
+        edges.sort_unstable();
+        assert_eq!(vec![(0, 1), (0, 2), (0, 3)], edges);
+        assert!((ChungLuFileGeneratorFactory
+            .try_with_params(vec![ParameterValue::String(path.clone(),)])
+            as Result<BoxedGenerator<Undirected, ThreadRng>>)
+            .is_err());
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,99 @@
+use super::{BoxedGenerator, GeneratorFactory};
+use crate::{Graph, NamedParam, ParameterType, ParameterValue};
+use anyhow::Result;
+use petgraph::EdgeType;
+use rand::Rng;
+
+/// A factory used to build generators for [complete bipartite graphs](https://en.wikipedia.org/wiki/Complete_bipartite_graph).
+///
+/// The first `m` nodes form the first part of the graph, while the `n` following ones form the second part.
+/// Each node of the first part is linked to each node of the second part.
+///
+/// In directed graphs generated by this objects, edges go from the first part to the second one.
+///
+/// Such factories can be created by passing `complete_bipartite/m,n` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str),
+/// where `m` and `n` are the sizes of the parts.
+#[derive(Default)]
+pub struct CompleteBipartiteGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for CompleteBipartiteGeneratorFactory
+where
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "complete_bipartite"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator producing a complete bipartite graph.",
+            "The parameters give the sizes of the two parts.",
+            "In directed graphs, edges go from the first part to the second one.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![
+            ParameterType::PositiveInteger,
+            ParameterType::PositiveInteger,
+        ]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let m = parameter_values[0].unwrap_usize();
+        let n = parameter_values[1].unwrap_usize();
+        Ok(Box::new(move |_| {
+            let mut g = Graph::with_capacity(m + n, m * n);
+            (0..m + n).for_each(|_| g.new_node());
+            (0..m).for_each(|i| (m..m + n).for_each(|j| g.new_edge(i, j)));
+            g
+        }))
+    }
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for CompleteBipartiteGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NodeIndexType;
+    use petgraph::Directed;
+
+    #[test]
+    fn test_empty_part() {
+        let mut rng = rand::thread_rng();
+        let g: Graph<Directed> = CompleteBipartiteGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(0),
+                ParameterValue::PositiveInteger(3),
+            ])
+            .unwrap()(&mut rng);
+        assert_eq!(3, g.n_nodes());
+        assert_eq!(0, g.n_edges());
+    }
+
+    #[test]
+    fn test_complete_bipartite() {
+        let mut rng = rand::thread_rng();
+        let g: Graph<Directed> = CompleteBipartiteGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(2),
+                ParameterValue::PositiveInteger(2),
+            ])
+            .unwrap()(&mut rng);
+        assert_eq!(4, g.n_nodes());
+        assert_eq!(
+            vec![(0, 2), (0, 3), (1, 2), (1, 3)],
+            g.iter_edges()
+                .collect::<Vec<(NodeIndexType, NodeIndexType)>>()
+        );
+    }
+}
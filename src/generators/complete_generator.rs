@@ -0,0 +1,122 @@
+use super::{BoxedGenerator, GeneratorFactory};
+use crate::{Graph, NamedParam, ParameterType, ParameterValue};
+use anyhow::Result;
+use petgraph::EdgeType;
+use rand::Rng;
+
+/// A factory used to build generators for [complete graphs](https://en.wikipedia.org/wiki/Complete_graph).
+///
+/// In directed graphs generated by this objects, each couple of distinct nodes is linked by two edges, one in each direction.
+///
+/// Such factories can be created by passing `complete/n` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str),
+/// where `n` is the number of nodes and must be at least 0.
+#[derive(Default)]
+pub struct CompleteGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for CompleteGeneratorFactory
+where
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "complete"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator producing a complete graph.",
+            "The first parameter gives the number of nodes.",
+            "In directed graphs, each couple of nodes is linked in both directions.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![ParameterType::PositiveInteger]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let n = parameter_values[0].unwrap_usize();
+        Ok(Box::new(move |_| {
+            let n_edges = if Ty::is_directed() {
+                n * n.saturating_sub(1)
+            } else {
+                n * n.saturating_sub(1) / 2
+            };
+            let mut g = Graph::with_capacity(n, n_edges);
+            (0..n).for_each(|_| g.new_node());
+            (0..n).for_each(|i| {
+                (i + 1..n).for_each(|j| {
+                    g.new_edge(i, j);
+                    if Ty::is_directed() {
+                        g.new_edge(j, i);
+                    }
+                })
+            });
+            g
+        }))
+    }
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for CompleteGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NodeIndexType;
+    use petgraph::{Directed, Undirected};
+
+    #[test]
+    fn test_complete_of_zero() {
+        let mut rng = rand::thread_rng();
+        let g: Graph<Directed> = CompleteGeneratorFactory
+            .try_with_params(vec![ParameterValue::PositiveInteger(0)])
+            .unwrap()(&mut rng);
+        assert_eq!(0, g.n_nodes());
+        assert_eq!(0, g.n_edges());
+    }
+
+    #[test]
+    fn test_complete_of_one() {
+        let mut rng = rand::thread_rng();
+        let g: Graph<Directed> = CompleteGeneratorFactory
+            .try_with_params(vec![ParameterValue::PositiveInteger(1)])
+            .unwrap()(&mut rng);
+        assert_eq!(1, g.n_nodes());
+        assert_eq!(0, g.n_edges());
+    }
+
+    #[test]
+    fn test_complete_undirected() {
+        let mut rng = rand::thread_rng();
+        let g: Graph<Undirected> = CompleteGeneratorFactory
+            .try_with_params(vec![ParameterValue::PositiveInteger(3)])
+            .unwrap()(&mut rng);
+        assert_eq!(3, g.n_nodes());
+        assert_eq!(
+            vec![(0, 1), (0, 2), (1, 2)],
+            g.iter_edges()
+                .collect::<Vec<(NodeIndexType, NodeIndexType)>>()
+        );
+    }
+
+    #[test]
+    fn test_complete_directed() {
+        let mut rng = rand::thread_rng();
+        let g: Graph<Directed> = CompleteGeneratorFactory
+            .try_with_params(vec![ParameterValue::PositiveInteger(3)])
+            .unwrap()(&mut rng);
+        assert_eq!(3, g.n_nodes());
+        let mut edges = g
+            .iter_edges()
+            .collect::<Vec<(NodeIndexType, NodeIndexType)>>();
+        edges.sort_unstable();
+        assert_eq!(vec![(0, 1), (0, 2), (1, 0), (1, 2), (2, 0), (2, 1)], edges);
+    }
+}
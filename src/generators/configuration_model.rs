@@ -0,0 +1,449 @@
+use super::{
+    degree_sequence::{self, DegreeDistribution},
+    BoxedGenerator, GeneratorFactory,
+};
+use crate::{Graph, NamedParam, NodeIndexType, ParameterType, ParameterValue};
+use anyhow::{anyhow, Context, Result};
+use petgraph::EdgeType;
+use rand::{seq::SliceRandom, Rng};
+use std::{collections::HashSet, str::FromStr};
+
+/// The policy applied to the self-loops and multi-edges produced by the configuration model.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum MultiEdgePolicy {
+    /// Self-loops and multi-edges are removed, so the graph is simple but degrees may be lower than requested
+    Drop,
+    /// Self-loops and multi-edges are kept, so degrees are exactly the requested ones
+    Keep,
+}
+
+impl FromStr for MultiEdgePolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "drop" => Ok(MultiEdgePolicy::Drop),
+            "keep" => Ok(MultiEdgePolicy::Keep),
+            _ => Err(anyhow!(
+                r#"unknown policy "{}" for self-loops and multi-edges (expected "drop" or "keep")"#,
+                s
+            )),
+        }
+    }
+}
+
+/// A factory used to build generators for graphs following the [configuration model](https://en.wikipedia.org/wiki/Configuration_model), with degree sequences sampled from a distribution.
+///
+/// Distributions are given by strings, which are one of:
+///   - `powerlaw:e:min:max` for a power law with exponent `e` and values between `min` and `max`;
+///   - `poisson:l` for a Poisson distribution with mean `l`;
+///   - `uniform:min:max` for a uniform distribution of the integers between `min` and `max` (both included).
+///
+/// In undirected graphs, such factories can be created by passing `config/n,d,policy` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str) where
+///   - `n` is the size of graph to produce;
+///   - `d` is the degree distribution;
+///   - `policy` is the policy applied to self-loops and multi-edges (`drop` or `keep`).
+///
+/// In case the sum of the sampled degrees is odd, the degree of a random node is incremented.
+///
+/// In directed graphs, such factories can be created by passing `config/n,dout,din,policy` where `dout` and `din` are the distributions of the outgoing and incoming degrees.
+/// While the sums of the sampled sequences differ, the degree of a random node of the sequence with the lowest sum is incremented.
+///
+/// With the `drop` policy, the generated graphs are simple, but some nodes may have lower degrees than the sampled ones.
+/// With the `keep` policy, the degrees are the sampled ones, but the graphs may contain self-loops and multi-edges.
+#[derive(Default)]
+pub struct ConfigurationModelGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for ConfigurationModelGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "config"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        if Ty::is_directed() {
+            vec![
+                "A generator following the configuration model, with degrees sampled from distributions.",
+                "First parameter gives the number of nodes, the second and the third ones the distributions of outgoing and incoming degrees.",
+                r#"Distributions are one of "powerlaw:e:min:max", "poisson:l" and "uniform:min:max"."#,
+                r#"The last parameter is "drop" to remove self-loops and multi-edges, or "keep" to keep them."#,
+            ]
+        } else {
+            vec![
+                "A generator following the configuration model, with degrees sampled from a distribution.",
+                "First parameter gives the number of nodes, the second one the degree distribution.",
+                r#"Distributions are one of "powerlaw:e:min:max", "poisson:l" and "uniform:min:max"."#,
+                r#"The last parameter is "drop" to remove self-loops and multi-edges, or "keep" to keep them."#,
+            ]
+        }
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        if Ty::is_directed() {
+            vec![
+                ParameterType::PositiveInteger,
+                ParameterType::String,
+                ParameterType::String,
+                ParameterType::String,
+            ]
+        } else {
+            vec![
+                ParameterType::PositiveInteger,
+                ParameterType::String,
+                ParameterType::String,
+            ]
+        }
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let context = "while building a configuration model generator";
+        let n = parameter_values[0].unwrap_usize();
+        let distributions = parameter_values[1..parameter_values.len() - 1]
+            .iter()
+            .map(|p| p.unwrap_str().parse::<DegreeDistribution>())
+            .collect::<Result<Vec<DegreeDistribution>>>()
+            .context(context)?;
+        let policy = parameter_values[parameter_values.len() - 1]
+            .unwrap_str()
+            .parse::<MultiEdgePolicy>()
+            .context(context)?;
+        Ok(Box::new(move |r| {
+            if Ty::is_directed() {
+                let mut out_degrees = distributions[0].sample_sequence(n, r);
+                let mut in_degrees = distributions[1].sample_sequence(n, r);
+                balance_sequences(&mut out_degrees, &mut in_degrees, r);
+                build_directed_graph(&out_degrees, &in_degrees, policy, r)
+            } else {
+                let mut degrees = distributions[0].sample_sequence(n, r);
+                if n > 0 && degrees.iter().sum::<usize>() & 1 == 1 {
+                    degrees[r.gen_range(0..n)] += 1;
+                }
+                build_undirected_graph(&degrees, policy, r)
+            }
+        }))
+    }
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for ConfigurationModelGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+/// A factory used to build generators for graphs following the [configuration model](https://en.wikipedia.org/wiki/Configuration_model), with degree sequences read from a file.
+///
+/// Such factories can be created by passing `config_file/path,policy` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str) where
+///   - `path` is the path to the file containing the degree sequence;
+///   - `policy` is the policy applied to self-loops and multi-edges (`drop` or `keep`).
+///
+/// The file is read once, when the factory is built.
+/// Empty lines and lines beginning with `#` are ignored.
+/// For undirected graphs, each other line must contain the degree of a node; the sum of the degrees must be even.
+/// For directed graphs, each other line must contain the outgoing and the incoming degrees of a node, separated by whitespaces;
+/// the sums of outgoing and incoming degrees must be equal.
+///
+/// With the `drop` policy, the generated graphs are simple, but some nodes may have lower degrees than the requested ones.
+/// With the `keep` policy, the degrees are the requested ones, but the graphs may contain self-loops and multi-edges.
+#[derive(Default)]
+pub struct ConfigurationModelFileGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for ConfigurationModelFileGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "config_file"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        if Ty::is_directed() {
+            vec![
+                "A generator following the configuration model, with degrees read from a file.",
+                "First parameter is the path to a file where each line gives the outgoing and incoming degrees of a node.",
+                r#"The second parameter is "drop" to remove self-loops and multi-edges, or "keep" to keep them."#,
+            ]
+        } else {
+            vec![
+                "A generator following the configuration model, with degrees read from a file.",
+                "First parameter is the path to a file where each line gives the degree of a node.",
+                r#"The second parameter is "drop" to remove self-loops and multi-edges, or "keep" to keep them."#,
+            ]
+        }
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![ParameterType::String, ParameterType::String]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let context = "while building a configuration model generator";
+        let path = parameter_values[0].unwrap_str();
+        let policy = parameter_values[1]
+            .unwrap_str()
+            .parse::<MultiEdgePolicy>()
+            .context(context)?;
+        if Ty::is_directed() {
+            let mut sequences =
+                degree_sequence::read_sequences::<usize>(path, 2).context(context)?;
+            let in_degrees = sequences.pop().unwrap();
+            let out_degrees = sequences.pop().unwrap();
+            let (sum_out, sum_in) = (
+                out_degrees.iter().sum::<usize>(),
+                in_degrees.iter().sum::<usize>(),
+            );
+            if sum_out != sum_in {
+                return Err(anyhow!(
+                    "the sum of outgoing degrees ({}) differs from the sum of incoming degrees ({})",
+                    sum_out,
+                    sum_in
+                ))
+                .context(context);
+            }
+            Ok(Box::new(move |r| {
+                build_directed_graph(&out_degrees, &in_degrees, policy, r)
+            }))
+        } else {
+            let degrees = degree_sequence::read_sequences::<usize>(path, 1)
+                .context(context)?
+                .pop()
+                .unwrap();
+            if degrees.iter().sum::<usize>() & 1 == 1 {
+                return Err(anyhow!("the sum of degrees must be even")).context(context);
+            }
+            Ok(Box::new(move |r| {
+                build_undirected_graph(&degrees, policy, r)
+            }))
+        }
+    }
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for ConfigurationModelFileGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+/// Increases random degrees of the smallest sequence until both sequences have the same sum.
+///
+/// If one of the sequences is empty, no edge can be built and all the degrees are set to zero.
+pub(super) fn balance_sequences<R>(degrees1: &mut [usize], degrees2: &mut [usize], r: &mut R)
+where
+    R: Rng,
+{
+    if degrees1.is_empty() || degrees2.is_empty() {
+        degrees1.iter_mut().for_each(|d| *d = 0);
+        degrees2.iter_mut().for_each(|d| *d = 0);
+        return;
+    }
+    let mut sum1 = degrees1.iter().sum::<usize>();
+    let mut sum2 = degrees2.iter().sum::<usize>();
+    while sum1 < sum2 {
+        degrees1[r.gen_range(0..degrees1.len())] += 1;
+        sum1 += 1;
+    }
+    while sum2 < sum1 {
+        degrees2[r.gen_range(0..degrees2.len())] += 1;
+        sum2 += 1;
+    }
+}
+
+/// Returns the stubs of a degree sequence, that is each node index repeated as many times as its degree.
+pub(super) fn stubs(degrees: &[usize]) -> Vec<NodeIndexType> {
+    degrees
+        .iter()
+        .enumerate()
+        .flat_map(|(i, d)| std::iter::repeat_n(i, *d))
+        .collect()
+}
+
+fn build_undirected_graph<Ty, R>(degrees: &[usize], policy: MultiEdgePolicy, r: &mut R) -> Graph<Ty>
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    let mut stubs = stubs(degrees);
+    stubs.shuffle(r);
+    let edges = stubs
+        .chunks_exact(2)
+        .map(|pair| (pair[0].min(pair[1]), pair[0].max(pair[1])));
+    build_graph(degrees.len(), stubs.len() / 2, edges, policy)
+}
+
+fn build_directed_graph<Ty, R>(
+    out_degrees: &[usize],
+    in_degrees: &[usize],
+    policy: MultiEdgePolicy,
+    r: &mut R,
+) -> Graph<Ty>
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    let out_stubs = stubs(out_degrees);
+    let mut in_stubs = stubs(in_degrees);
+    in_stubs.shuffle(r);
+    let edges = out_stubs.iter().copied().zip(in_stubs.iter().copied());
+    build_graph(out_degrees.len(), out_stubs.len(), edges, policy)
+}
+
+fn build_graph<Ty, I>(n: usize, n_edges: usize, edges: I, policy: MultiEdgePolicy) -> Graph<Ty>
+where
+    I: Iterator<Item = (NodeIndexType, NodeIndexType)>,
+    Ty: EdgeType,
+{
+    let mut g = Graph::with_capacity(n, n_edges);
+    (0..n).for_each(|_| g.new_node());
+    let mut added = HashSet::with_capacity(n_edges);
+    edges.for_each(|(i, j)| {
+        if policy == MultiEdgePolicy::Keep || (i != j && added.insert((i, j))) {
+            g.new_edge(i, j);
+        }
+    });
+    g
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::write_tmp_file;
+    use petgraph::{Directed, Undirected};
+    use rand::{rngs::ThreadRng, SeedableRng};
+    use rand_pcg::Pcg32;
+
+    fn degrees<Ty>(g: &Graph<Ty>) -> (Vec<usize>, Vec<usize>)
+    where
+        Ty: EdgeType,
+    {
+        let mut out_degrees = vec![0; g.n_nodes()];
+        let mut in_degrees = vec![0; g.n_nodes()];
+        g.iter_edges().for_each(|(i, j)| {
+            out_degrees[i] += 1;
+            in_degrees[j] += 1;
+        });
+        (out_degrees, in_degrees)
+    }
+
+    #[test]
+    fn test_unknown_policy() {
+        assert!((ConfigurationModelGeneratorFactory.try_with_params(vec![
+            ParameterValue::PositiveInteger(10),
+            ParameterValue::String("uniform:1:2".to_string()),
+            ParameterValue::String("foo".to_string()),
+        ]) as Result<BoxedGenerator<Undirected, ThreadRng>>)
+            .is_err())
+    }
+
+    #[test]
+    fn test_unknown_distribution() {
+        assert!((ConfigurationModelGeneratorFactory.try_with_params(vec![
+            ParameterValue::PositiveInteger(10),
+            ParameterValue::String("foo:1".to_string()),
+            ParameterValue::String("drop".to_string()),
+        ]) as Result<BoxedGenerator<Undirected, ThreadRng>>)
+            .is_err())
+    }
+
+    #[test]
+    fn test_sampled_keep_undirected() {
+        let g: Graph<Undirected> = ConfigurationModelGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(10),
+                ParameterValue::String("uniform:3:3".to_string()),
+                ParameterValue::String("keep".to_string()),
+            ])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(10, g.n_nodes());
+        assert_eq!(15, g.n_edges());
+    }
+
+    #[test]
+    fn test_sampled_keep_directed() {
+        let g: Graph<Directed> = ConfigurationModelGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(10),
+                ParameterValue::String("uniform:2:2".to_string()),
+                ParameterValue::String("uniform:2:2".to_string()),
+                ParameterValue::String("keep".to_string()),
+            ])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!((vec![2; 10], vec![2; 10]), degrees(&g));
+    }
+
+    #[test]
+    fn test_sampled_drop_is_simple() {
+        let g: Graph<Directed> = ConfigurationModelGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(5),
+                ParameterValue::String("uniform:4:4".to_string()),
+                ParameterValue::String("uniform:4:4".to_string()),
+                ParameterValue::String("drop".to_string()),
+            ])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        let edges = g.iter_edges().collect::<Vec<_>>();
+        assert!(edges.iter().all(|(i, j)| i != j));
+        assert_eq!(edges.len(), edges.iter().collect::<HashSet<_>>().len());
+    }
+
+    #[test]
+    fn test_file_directed() {
+        let path = write_tmp_file("config_file_directed.txt", "1 0\n0 2\n1 0\n");
+        let g: Graph<Directed> = ConfigurationModelFileGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::String(path.clone()),
+                ParameterValue::String("keep".to_string()),
+            ])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        std::fs::remove_file(path).unwrap();
+        let mut edges = g.iter_edges().collect::<Vec<_>>();
+        edges.sort_unstable();
+        assert_eq!(vec![(0, 1), (2, 1)], edges);
+    }
+
+    #[test]
+    fn test_file_directed_unbalanced() {
+        let path = write_tmp_file("config_file_directed_unbalanced.txt", "1 0\n0 2\n");
+        let factory_result = ConfigurationModelFileGeneratorFactory.try_with_params(vec![
+            ParameterValue::String(path.clone()),
+            ParameterValue::String("keep".to_string()),
+        ]) as Result<BoxedGenerator<Directed, ThreadRng>>;
+        std::fs::remove_file(path).unwrap();
+        assert!(factory_result.is_err());
+    }
+
+    #[test]
+    fn test_file_undirected_odd_sum() {
+        let path = write_tmp_file("config_file_undirected_odd.txt", "1\n2\n");
+        let factory_result = ConfigurationModelFileGeneratorFactory.try_with_params(vec![
+            ParameterValue::String(path.clone()),
+            ParameterValue::String("keep".to_string()),
+        ]) as Result<BoxedGenerator<Undirected, ThreadRng>>;
+        std::fs::remove_file(path).unwrap();
+        assert!(factory_result.is_err());
+    }
+
+    #[test]
+    fn test_file_undirected() {
+        let path = write_tmp_file("config_file_undirected.txt", "# a path\n1\n2\n1\n");
+        let g: Graph<Undirected> = ConfigurationModelFileGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::String(path.clone()),
+                ParameterValue::String("keep".to_string()),
+            ])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        std::fs::remove_file(path).unwrap();
+        assert_eq!(3, g.n_nodes());
+        assert_eq!(2, g.n_edges());
+    }
+}
@@ -0,0 +1,150 @@
+use super::{BoxedGenerator, GeneratorFactory};
+use crate::{Graph, NamedParam, NodeIndexType, ParameterType, ParameterValue};
+use anyhow::{anyhow, Context, Result};
+use petgraph::Directed;
+use rand::Rng;
+
+/// A factory used to build generators for graphs following the [copying model](https://doi.org/10.1109/SFCS.2000.892065) of Kleinberg, Kumar et al.
+///
+/// The process starts with a complete directed graph of `d+1` nodes, in which each node has `d` outgoing edges.
+/// Then, each new node chooses a prototype node uniformly at random among the existing ones, and adds `d` outgoing edges:
+/// the `i`-th edge goes to the target of the `i`-th outgoing edge of the prototype with probability `p`, or to a node chosen uniformly at random otherwise.
+/// In case the chosen target is already linked from the new node, a node chosen uniformly at random is used instead.
+/// The in-degrees of the resulting graphs follow a power law, and the graphs contain many dense bipartite cores, like the Web graph.
+///
+/// This generator is only available for directed graphs.
+///
+/// Such factories can be created by passing `copying/n,d,p` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str) where
+///   - `n` is the size of graph to produce;
+///   - `d` is the out-degree of the nodes;
+///   - `p` is the probability to copy an edge of the prototype.
+///
+/// Parameter `d` must be lower than `n`.
+#[derive(Default)]
+pub struct CopyingModelGeneratorFactory;
+
+impl<R> NamedParam<BoxedGenerator<Directed, R>> for CopyingModelGeneratorFactory
+where
+    R: Rng,
+{
+    fn name(&self) -> &'static str {
+        "copying"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator following the copying model.",
+            "First parameter gives the number of nodes, the second one the out-degree of the nodes, and the third one the probability to copy an edge of the prototype.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![
+            ParameterType::PositiveInteger,
+            ParameterType::PositiveInteger,
+            ParameterType::Probability,
+        ]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Directed, R>> {
+        let n = parameter_values[0].unwrap_usize();
+        let d = parameter_values[1].unwrap_usize();
+        let p = parameter_values[2].unwrap_f64();
+        if d >= n {
+            return Err(anyhow!(
+                r#"second parameter ("d") must be lower than the first one ("n")"#
+            ))
+            .context("while building a copying model generator");
+        }
+        Ok(Box::new(move |r| build_graph(n, d, p, r)))
+    }
+}
+
+impl<R> GeneratorFactory<Directed, R> for CopyingModelGeneratorFactory where R: Rng {}
+
+fn build_graph<R>(n: usize, d: usize, p: f64, r: &mut R) -> Graph<Directed>
+where
+    R: Rng,
+{
+    let mut out_neighbors: Vec<Vec<NodeIndexType>> = (0..=d)
+        .map(|i| (0..=d).filter(|j| *j != i).collect())
+        .collect();
+    for v in d + 1..n {
+        let prototype = r.gen_range(0..v);
+        let mut targets = Vec::with_capacity(d);
+        for copied in out_neighbors[prototype].iter() {
+            let mut target = if r.gen_bool(p) {
+                *copied
+            } else {
+                r.gen_range(0..v)
+            };
+            while targets.contains(&target) {
+                target = r.gen_range(0..v);
+            }
+            targets.push(target);
+        }
+        out_neighbors.push(targets);
+    }
+    let mut g = Graph::with_capacity(n, n * d);
+    (0..n).for_each(|_| g.new_node());
+    out_neighbors
+        .into_iter()
+        .enumerate()
+        .for_each(|(v, targets)| targets.into_iter().for_each(|w| g.new_edge(v, w)));
+    g
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::ThreadRng, SeedableRng};
+    use rand_pcg::Pcg32;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_d_not_lower_than_n() {
+        assert!((CopyingModelGeneratorFactory.try_with_params(vec![
+            ParameterValue::PositiveInteger(3),
+            ParameterValue::PositiveInteger(3),
+            ParameterValue::Probability(0.5),
+        ]) as Result<BoxedGenerator<Directed, ThreadRng>>)
+            .is_err())
+    }
+
+    #[test]
+    fn test_initial_graph() {
+        let g: Graph<Directed> = CopyingModelGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(3),
+                ParameterValue::PositiveInteger(2),
+                ParameterValue::Probability(0.5),
+            ])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(
+            vec![(0, 1), (0, 2), (1, 0), (1, 2), (2, 0), (2, 1)],
+            g.iter_edges()
+                .collect::<Vec<(NodeIndexType, NodeIndexType)>>()
+        );
+    }
+
+    #[test]
+    fn test_out_degrees() {
+        let g: Graph<Directed> = CopyingModelGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(200),
+                ParameterValue::PositiveInteger(4),
+                ParameterValue::Probability(0.8),
+            ])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(200, g.n_nodes());
+        assert_eq!(800, g.n_edges());
+        let mut edges = HashSet::new();
+        g.iter_edges().for_each(|(v, w)| {
+            assert_ne!(v, w);
+            assert!(edges.insert((v, w)));
+        });
+    }
+}
@@ -0,0 +1,114 @@
+use super::{BoxedGenerator, GeneratorFactory};
+use crate::{Graph, NamedParam, ParameterType, ParameterValue};
+use anyhow::Result;
+use petgraph::EdgeType;
+use rand::Rng;
+
+/// A factory used to build generators for [cycle graphs](https://en.wikipedia.org/wiki/Cycle_graph).
+///
+/// In directed graphs generated by this objects, edges go from nodes to the ones with the same index plus one, and from the last node to the first one.
+///
+/// Graphs with less than 3 nodes cannot be cycles without self-loops nor multi-edges; in this case, a path is produced.
+///
+/// Such factories can be created by passing `cycle/n` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str),
+/// where `n` is the length of cycle to produce and must be at least 0.
+#[derive(Default)]
+pub struct CycleGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for CycleGeneratorFactory
+where
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "cycle"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator producing a cycle graph.",
+            "The first parameter gives the length of the cycle.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![ParameterType::PositiveInteger]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let n = parameter_values[0].unwrap_usize();
+        Ok(Box::new(move |_| {
+            let mut g = Graph::with_capacity(n, n);
+            (0..n).for_each(|_| g.new_node());
+            add_cycle(&mut g, &(0..n).collect::<Vec<usize>>());
+            g
+        }))
+    }
+}
+
+/// Adds the edges of a cycle following the provided nodes.
+///
+/// If less than 3 nodes are given, the edges of a path are added instead.
+pub(crate) fn add_cycle<Ty>(g: &mut Graph<Ty>, nodes: &[usize])
+where
+    Ty: EdgeType,
+{
+    nodes.windows(2).for_each(|w| g.new_edge(w[0], w[1]));
+    if nodes.len() >= 3 {
+        g.new_edge(nodes[nodes.len() - 1], nodes[0]);
+    }
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for CycleGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NodeIndexType;
+    use petgraph::Directed;
+
+    #[test]
+    fn test_cycle_of_zero() {
+        let mut rng = rand::thread_rng();
+        let g: Graph<Directed> = CycleGeneratorFactory
+            .try_with_params(vec![ParameterValue::PositiveInteger(0)])
+            .unwrap()(&mut rng);
+        assert_eq!(0, g.n_nodes());
+        assert_eq!(0, g.n_edges());
+    }
+
+    #[test]
+    fn test_cycle_of_two() {
+        let mut rng = rand::thread_rng();
+        let g: Graph<Directed> = CycleGeneratorFactory
+            .try_with_params(vec![ParameterValue::PositiveInteger(2)])
+            .unwrap()(&mut rng);
+        assert_eq!(2, g.n_nodes());
+        assert_eq!(
+            vec![(0, 1)],
+            g.iter_edges()
+                .collect::<Vec<(NodeIndexType, NodeIndexType)>>()
+        );
+    }
+
+    #[test]
+    fn test_cycle() {
+        let mut rng = rand::thread_rng();
+        let g: Graph<Directed> = CycleGeneratorFactory
+            .try_with_params(vec![ParameterValue::PositiveInteger(4)])
+            .unwrap()(&mut rng);
+        assert_eq!(4, g.n_nodes());
+        assert_eq!(
+            vec![(0, 1), (1, 2), (2, 3), (3, 0)],
+            g.iter_edges()
+                .collect::<Vec<(NodeIndexType, NodeIndexType)>>()
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use super::power_law::PowerLaw;
+use anyhow::{anyhow, Context, Result};
+use rand::{distributions::Uniform, prelude::Distribution, Rng};
+use rand_distr::Poisson;
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+    str::FromStr,
+};
+
+/// A distribution used to sample degree sequences.
+///
+/// Such distributions are built from strings, which are one of:
+///   - `powerlaw:e:min:max` for a power law with exponent `e` and values between `min` and `max`;
+///   - `poisson:l` for a Poisson distribution with mean `l`;
+///   - `uniform:min:max` for a uniform distribution of the integers between `min` and `max` (both included).
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum DegreeDistribution {
+    PowerLaw(PowerLaw),
+    Poisson(Poisson<f64>),
+    Uniform(Uniform<usize>),
+}
+
+impl DegreeDistribution {
+    /// Samples a sequence of `n` degrees.
+    pub(crate) fn sample_sequence<R>(&self, n: usize, r: &mut R) -> Vec<usize>
+    where
+        R: Rng,
+    {
+        (0..n).map(|_| self.sample(r)).collect()
+    }
+}
+
+impl Distribution<usize> for DegreeDistribution {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        match self {
+            DegreeDistribution::PowerLaw(d) => d.sample(rng).round() as usize,
+            DegreeDistribution::Poisson(d) => d.sample(rng) as usize,
+            DegreeDistribution::Uniform(d) => d.sample(rng),
+        }
+    }
+}
+
+impl FromStr for DegreeDistribution {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let context = || format!(r#"while parsing the degree distribution "{}""#, s);
+        let words = s.split(':').collect::<Vec<&str>>();
+        let parse_f64 = |w: &str| -> Result<f64> {
+            let f = str::parse::<f64>(w)
+                .with_context(|| format!(r#"while parsing "{}" as a number"#, w))?;
+            if !f.is_finite() || f < 0. {
+                return Err(anyhow!("value must be a finite positive number"));
+            }
+            Ok(f)
+        };
+        let parse_usize = |w: &str| -> Result<usize> {
+            str::parse::<usize>(w)
+                .with_context(|| format!(r#"while parsing "{}" as a positive integer"#, w))
+        };
+        match (words[0], words.len()) {
+            ("powerlaw", 4) => {
+                let exponent = parse_f64(words[1]).with_context(context)?;
+                let min = parse_usize(words[2]).with_context(context)?;
+                let max = parse_usize(words[3]).with_context(context)?;
+                if min == 0 || min > max {
+                    return Err(anyhow!(
+                        "power law bounds must be higher than 0 and ordered"
+                    ))
+                    .with_context(context);
+                }
+                Ok(DegreeDistribution::PowerLaw(PowerLaw::new(
+                    min as f64, max as f64, exponent,
+                )))
+            }
+            ("poisson", 2) => {
+                let mean = parse_f64(words[1]).with_context(context)?;
+                Ok(DegreeDistribution::Poisson(
+                    Poisson::new(mean)
+                        .map_err(|e| anyhow!("{}", e))
+                        .with_context(context)?,
+                ))
+            }
+            ("uniform", 3) => {
+                let min = parse_usize(words[1]).with_context(context)?;
+                let max = parse_usize(words[2]).with_context(context)?;
+                if min > max {
+                    return Err(anyhow!("uniform bounds must be ordered")).with_context(context);
+                }
+                Ok(DegreeDistribution::Uniform(Uniform::new_inclusive(
+                    min, max,
+                )))
+            }
+            _ => Err(anyhow!(
+                r#"expected "powerlaw:e:min:max", "poisson:l" or "uniform:min:max""#
+            ))
+            .with_context(context),
+        }
+    }
+}
+
+/// Reads sequences of numbers from a file, one column per sequence.
+///
+/// Each non-empty line which does not begin with a `#` must contain exactly `n_columns` values, separated by whitespaces.
+/// The values of the i-th column are returned in the i-th vector.
+pub(crate) fn read_sequences<T>(path: &str, n_columns: usize) -> Result<Vec<Vec<T>>>
+where
+    T: FromStr,
+    <T as FromStr>::Err: std::error::Error + Send + Sync + 'static,
+{
+    let context = || format!(r#"while reading sequences from file "{}""#, path);
+    let file = File::open(path).with_context(context)?;
+    let mut sequences = (0..n_columns).map(|_| vec![]).collect::<Vec<Vec<T>>>();
+    for (i, line) in BufReader::new(file).lines().enumerate() {
+        let line = line.with_context(context)?;
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let words = trimmed.split_whitespace().collect::<Vec<&str>>();
+        if words.len() != n_columns {
+            return Err(anyhow!(
+                "expected {} values at line {}, got {}",
+                n_columns,
+                i + 1,
+                words.len()
+            ))
+            .with_context(context);
+        }
+        for (j, w) in words.into_iter().enumerate() {
+            sequences[j].push(
+                str::parse::<T>(w)
+                    .with_context(|| format!(r#"while parsing value "{}" at line {}"#, w, i + 1))
+                    .with_context(context)?,
+            );
+        }
+    }
+    Ok(sequences)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::write_tmp_file;
+    use rand::SeedableRng;
+    use rand_pcg::Pcg32;
+
+    #[test]
+    fn test_parse_distributions_ok() {
+        assert!(matches!(
+            "powerlaw:2.5:1:10".parse::<DegreeDistribution>(),
+            Ok(DegreeDistribution::PowerLaw(_))
+        ));
+        assert!(matches!(
+            "poisson:3".parse::<DegreeDistribution>(),
+            Ok(DegreeDistribution::Poisson(_))
+        ));
+        assert!(matches!(
+            "uniform:2:2".parse::<DegreeDistribution>(),
+            Ok(DegreeDistribution::Uniform(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_distributions_not_ok() {
+        assert!("foo:1".parse::<DegreeDistribution>().is_err());
+        assert!("powerlaw:2.5:0:10".parse::<DegreeDistribution>().is_err());
+        assert!("powerlaw:2.5:10".parse::<DegreeDistribution>().is_err());
+        assert!("poisson:-1".parse::<DegreeDistribution>().is_err());
+        assert!("uniform:3:2".parse::<DegreeDistribution>().is_err());
+    }
+
+    #[test]
+    fn test_sample_uniform() {
+        let d = "uniform:2:2".parse::<DegreeDistribution>().unwrap();
+        assert_eq!(
+            vec![2, 2, 2],
+            d.sample_sequence(3, &mut Pcg32::seed_from_u64(0))
+        );
+    }
+
+    #[test]
+    fn test_read_sequences() {
+        let path = write_tmp_file("read_sequences.txt", "# out in\n1 2\n\n3 4\n");
+        let sequences = read_sequences::<usize>(&path, 2).unwrap();
+        assert_eq!(vec![vec![1, 3], vec![2, 4]], sequences);
+        assert!(read_sequences::<usize>(&path, 1).is_err());
+        std::fs::remove_file(path).unwrap();
+    }
+}
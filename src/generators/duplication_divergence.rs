@@ -0,0 +1,188 @@
+use super::{BoxedGenerator, GeneratorFactory};
+use crate::{Graph, NamedParam, NodeIndexType, ParameterType, ParameterValue};
+use anyhow::{anyhow, Context, Result};
+use petgraph::EdgeType;
+use rand::Rng;
+
+/// A factory used to build generators for graphs following the [duplication–divergence](https://doi.org/10.1103/PhysRevE.71.061911) model,
+/// which mimics the evolution of protein interaction networks.
+///
+/// The process starts with two linked nodes.
+/// Then, each new node is a duplicate of a node chosen uniformly at random among the existing ones: each edge of this node is copied with probability `p`.
+/// In case no edge is copied, the new node is discarded and another one is chosen to be duplicated.
+///
+/// In directed graphs generated by this objects, the initial edge goes from the first node to the second one,
+/// and both the incoming and the outgoing edges of the duplicated nodes are copied (with their directions).
+///
+/// Such factories can be created by passing `dd/n,p` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str) where
+///   - `n` is the size of graph to produce;
+///   - `p` is the probability to keep each copied edge.
+///
+/// Parameter `p` must be higher than zero.
+#[derive(Default)]
+pub struct DuplicationDivergenceGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for DuplicationDivergenceGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "dd"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator following the duplication–divergence model.",
+            "First parameter gives the number of nodes, while the second one gives the probability to keep each edge of a duplicated node.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![ParameterType::PositiveInteger, ParameterType::Probability]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let n = parameter_values[0].unwrap_usize();
+        let p = parameter_values[1].unwrap_f64();
+        if p == 0. {
+            return Err(anyhow!(r#"second parameter ("p") must be higher than 0"#))
+                .context("while building a duplication–divergence generator");
+        }
+        Ok(Box::new(move |r| build_graph(n, p, r)))
+    }
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for DuplicationDivergenceGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+fn build_graph<Ty, R>(n: usize, p: f64, r: &mut R) -> Graph<Ty>
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    let mut g = Graph::with_capacity(n, 0);
+    (0..n).for_each(|_| g.new_node());
+    if n < 2 {
+        return g;
+    }
+    let directed = Ty::is_directed();
+    let mut out_neighbors: Vec<Vec<NodeIndexType>> = vec![vec![1], vec![]];
+    let mut in_neighbors: Vec<Vec<NodeIndexType>> = vec![vec![], vec![0]];
+    if !directed {
+        out_neighbors[1].push(0);
+    }
+    let mut edges = vec![(0, 1)];
+    for v in 2..n {
+        let (copied_out, copied_in) = loop {
+            let prototype = r.gen_range(0..v);
+            let copied_out = out_neighbors[prototype]
+                .iter()
+                .copied()
+                .filter(|_| r.gen_bool(p))
+                .collect::<Vec<NodeIndexType>>();
+            let copied_in = in_neighbors[prototype]
+                .iter()
+                .copied()
+                .filter(|_| directed && r.gen_bool(p))
+                .collect::<Vec<NodeIndexType>>();
+            if !copied_out.is_empty() || !copied_in.is_empty() {
+                break (copied_out, copied_in);
+            }
+        };
+        copied_out.iter().for_each(|w| {
+            edges.push((v, *w));
+            if directed {
+                in_neighbors[*w].push(v);
+            } else {
+                out_neighbors[*w].push(v);
+            }
+        });
+        copied_in.iter().for_each(|u| {
+            edges.push((*u, v));
+            out_neighbors[*u].push(v);
+        });
+        out_neighbors.push(copied_out);
+        in_neighbors.push(copied_in);
+    }
+    edges.into_iter().for_each(|(i, j)| g.new_edge(i, j));
+    g
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::{Directed, Undirected};
+    use rand::{rngs::ThreadRng, SeedableRng};
+    use rand_pcg::Pcg32;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_probability_0() {
+        assert!((DuplicationDivergenceGeneratorFactory.try_with_params(vec![
+            ParameterValue::PositiveInteger(10),
+            ParameterValue::Probability(0.),
+        ]) as Result<BoxedGenerator<Undirected, ThreadRng>>)
+            .is_err())
+    }
+
+    #[test]
+    fn test_probability_1() {
+        let g: Graph<Undirected> = DuplicationDivergenceGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(6),
+                ParameterValue::Probability(1.),
+            ])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(6, g.n_nodes());
+        let mut degrees = [0; 6];
+        g.iter_edges().for_each(|(i, j)| {
+            degrees[i] += 1;
+            degrees[j] += 1;
+        });
+        assert!(g.iter_edges().all(|(i, j)| degrees[i] + degrees[j] == 6));
+    }
+
+    #[test]
+    fn test_simple_graph() {
+        let g: Graph<Undirected> = DuplicationDivergenceGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(200),
+                ParameterValue::Probability(0.4),
+            ])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(200, g.n_nodes());
+        let mut degrees = vec![0; 200];
+        let mut edges = HashSet::new();
+        g.iter_edges().for_each(|(i, j)| {
+            assert_ne!(i, j);
+            assert!(edges.insert((i.min(j), i.max(j))));
+            degrees[i] += 1;
+            degrees[j] += 1;
+        });
+        assert!(degrees.iter().all(|d| *d > 0));
+    }
+
+    #[test]
+    fn test_directed() {
+        let g: Graph<Directed> = DuplicationDivergenceGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(100),
+                ParameterValue::Probability(0.5),
+            ])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        let mut edges = HashSet::new();
+        g.iter_edges().for_each(|(i, j)| {
+            assert_ne!(i, j);
+            assert!(edges.insert((i, j)));
+            assert!(!edges.contains(&(j, i)));
+        });
+    }
+}
@@ -1,18 +1,20 @@
 use super::{BoxedGenerator, GeneratorFactory};
-use crate::{NamedParam, ParameterType, ParameterValue};
-use anyhow::Result;
+use crate::{Graph, NamedParam, NodeIndexType, ParameterType, ParameterValue};
+use anyhow::{anyhow, Context, Result};
 use petgraph::EdgeType;
-use rand::Rng;
+use rand::{seq::index, Rng};
 
 /// A factory used to build generators for [Erdős–Rényi](https://en.wikipedia.org/wiki/Erd%C5%91s%E2%80%93R%C3%A9nyi_model) graphs.
 ///
 /// In directed graphs generated by this objects, for each pair of nodes, both edges are considered for addition (0, 1 or 2 edges can be generated).
 ///
+/// Edges are sampled using the geometric skipping method of [Batagelj and Brandes](https://doi.org/10.1103/PhysRevE.71.036113),
+/// which runs in time linear in the number of nodes and edges instead of testing every pair of nodes.
+///
 /// Such factories can be created by passing `er/n,p` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str) where
 ///   - `n` is the size of graph to produce;
 ///   - `p` is the probability each edge appears in the graph.
 ///
-/// Graphs used for initialization are star graphs.
 /// Parameters must be higher than zero, and `p` must be a floating point number between 0 and 1.
 #[derive(Default)]
 pub struct ErdosRenyiGeneratorFactory;
@@ -43,12 +45,40 @@ where
     ) -> Result<BoxedGenerator<Ty, R>> {
         let n = parameter_values[0].unwrap_usize();
         let p = parameter_values[1].unwrap_f64();
+        let pairs = PairIndexer::try_new(n, Ty::is_directed())
+            .context("while building an Erdős–Rényi generator")?;
         Ok(Box::new(move |r| {
-            petgraph_gen::random_gnp_graph(r, n, p).into()
+            pairs.build_graph(&sample_pair_indices(pairs.n_pairs(), p, r))
         }))
     }
 }
 
+/// Selects each index in `0..n_pairs` with probability `p`, and returns the selected ones in increasing order.
+///
+/// Instead of drawing a random number for each index, the gaps between the selected indices are drawn from a geometric distribution.
+pub(crate) fn sample_pair_indices<R>(n_pairs: usize, p: f64, r: &mut R) -> Vec<usize>
+where
+    R: Rng,
+{
+    let mut indices = vec![];
+    if p == 0. {
+        return indices;
+    }
+    let log_q = (-p).ln_1p();
+    let mut next = 0_usize;
+    loop {
+        if p < 1. {
+            let x = 1. - r.gen::<f64>();
+            next = next.saturating_add((x.ln() / log_q).floor() as usize);
+        }
+        if next >= n_pairs {
+            return indices;
+        }
+        indices.push(next);
+        next += 1;
+    }
+}
+
 impl<Ty, R> GeneratorFactory<Ty, R> for ErdosRenyiGeneratorFactory
 where
     R: Rng,
@@ -56,11 +86,141 @@ where
 {
 }
 
+/// A factory used to build generators for [Erdős–Rényi](https://en.wikipedia.org/wiki/Erd%C5%91s%E2%80%93R%C3%A9nyi_model) graphs with a fixed number of edges, also known as G(n, m) graphs.
+///
+/// The edges are chosen uniformly among all the sets of `m` distinct pairs of nodes; self-loops are not allowed.
+/// In directed graphs generated by this objects, both edges between two nodes are considered as distinct pairs.
+///
+/// Such factories can be created by passing `gnm/n,m` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str) where
+///   - `n` is the size of graph to produce;
+///   - `m` is the number of edges.
+///
+/// Parameter `m` must not exceed the number of pairs of nodes, that is `n*(n-1)/2` for undirected graphs and `n*(n-1)` for directed ones.
+#[derive(Default)]
+pub struct ErdosRenyiGnmGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for ErdosRenyiGnmGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "gnm"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator following the Erdős–Rényi model with a fixed number of edges.",
+            "First parameter gives the number of nodes of the graph, while the second one gives its number of edges.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![
+            ParameterType::PositiveInteger,
+            ParameterType::PositiveInteger,
+        ]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let context = "while building an Erdős–Rényi G(n, m) generator";
+        let n = parameter_values[0].unwrap_usize();
+        let m = parameter_values[1].unwrap_usize();
+        let pairs = PairIndexer::try_new(n, Ty::is_directed()).context(context)?;
+        if m > pairs.n_pairs() {
+            return Err(anyhow!(
+                r#"second parameter ("m") must not exceed the number of pairs of nodes ({})"#,
+                pairs.n_pairs()
+            ))
+            .context(context);
+        }
+        Ok(Box::new(move |r| {
+            let mut indices = index::sample(r, pairs.n_pairs(), m).into_vec();
+            indices.sort_unstable();
+            pairs.build_graph(&indices)
+        }))
+    }
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for ErdosRenyiGnmGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+/// Maps the pairs of distinct nodes to consecutive indices.
+///
+/// In the undirected case, the pairs `(j, i)` with `j < i` are ordered by `i`, then by `j`.
+/// In the directed case, the pairs `(i, j)` with `i != j` are ordered by `i`, then by `j`.
+struct PairIndexer {
+    n: usize,
+    directed: bool,
+    n_pairs: usize,
+}
+
+impl PairIndexer {
+    fn try_new(n: usize, directed: bool) -> Result<Self> {
+        let n_ordered_pairs = n
+            .checked_mul(n.saturating_sub(1))
+            .ok_or_else(|| anyhow!("too many nodes"))?;
+        Ok(Self {
+            n,
+            directed,
+            n_pairs: if directed {
+                n_ordered_pairs
+            } else {
+                n_ordered_pairs / 2
+            },
+        })
+    }
+
+    fn n_pairs(&self) -> usize {
+        self.n_pairs
+    }
+
+    fn row_len(&self, i: usize) -> usize {
+        if self.directed {
+            self.n - 1
+        } else {
+            i
+        }
+    }
+
+    /// Builds a graph which edges are given by a sorted list of pair indices.
+    fn build_graph<Ty>(&self, sorted_indices: &[usize]) -> Graph<Ty>
+    where
+        Ty: EdgeType,
+    {
+        let mut g = Graph::with_capacity(self.n, sorted_indices.len());
+        (0..self.n).for_each(|_| g.new_node());
+        let mut row: NodeIndexType = 0;
+        let mut row_start = 0;
+        sorted_indices.iter().for_each(|index| {
+            while *index >= row_start + self.row_len(row) {
+                row_start += self.row_len(row);
+                row += 1;
+            }
+            let offset = index - row_start;
+            if self.directed {
+                g.new_edge(row, if offset < row { offset } else { offset + 1 });
+            } else {
+                g.new_edge(offset, row);
+            }
+        });
+        g
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{Graph, NodeIndexType};
-    use petgraph::Directed;
+    use petgraph::{Directed, Undirected};
+    use rand::{rngs::ThreadRng, SeedableRng};
+    use rand_pcg::Pcg32;
 
     #[test]
     fn test_probability_0() {
@@ -95,4 +255,100 @@ mod tests {
         edges.sort_unstable();
         assert_eq!(vec![(0, 1), (0, 2), (1, 0), (1, 2), (2, 0), (2, 1)], edges);
     }
+
+    #[test]
+    fn test_probability_1_undirected() {
+        let mut rng = rand::thread_rng();
+        let g: Graph<Undirected> = ErdosRenyiGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(4),
+                ParameterValue::Probability(1.0),
+            ])
+            .unwrap()(&mut rng);
+        let mut edges = g
+            .iter_edges()
+            .map(|(i, j)| (i.min(j), i.max(j)))
+            .collect::<Vec<(NodeIndexType, NodeIndexType)>>();
+        edges.sort_unstable();
+        assert_eq!(vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)], edges);
+    }
+
+    #[test]
+    fn test_sparse_edge_count() {
+        let g: Graph<Undirected> = ErdosRenyiGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(10_000),
+                ParameterValue::Probability(0.001),
+            ])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        let expected = 0.001 * (10_000. * 9_999. / 2.);
+        assert!((g.n_edges() as f64 - expected).abs() < 0.05 * expected);
+    }
+
+    #[test]
+    fn test_gnm_too_many_edges() {
+        assert!((ErdosRenyiGnmGeneratorFactory.try_with_params(vec![
+            ParameterValue::PositiveInteger(3),
+            ParameterValue::PositiveInteger(4)
+        ]) as Result<BoxedGenerator<Undirected, ThreadRng>>)
+            .is_err());
+        assert!((ErdosRenyiGnmGeneratorFactory.try_with_params(vec![
+            ParameterValue::PositiveInteger(3),
+            ParameterValue::PositiveInteger(6)
+        ]) as Result<BoxedGenerator<Directed, ThreadRng>>)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_gnm_edge_count() {
+        let mut rng = Pcg32::seed_from_u64(0);
+        let generator = ErdosRenyiGnmGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(20),
+                ParameterValue::PositiveInteger(50),
+            ])
+            .unwrap();
+        let g: Graph<Directed> = generator(&mut rng);
+        let mut edges = g
+            .iter_edges()
+            .collect::<Vec<(NodeIndexType, NodeIndexType)>>();
+        edges.sort_unstable();
+        edges.dedup();
+        assert_eq!(50, edges.len());
+        assert!(edges.iter().all(|(i, j)| i != j));
+    }
+
+    #[test]
+    fn test_gnm_complete() {
+        let g: Graph<Undirected> = ErdosRenyiGnmGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(3),
+                ParameterValue::PositiveInteger(3),
+            ])
+            .unwrap()(&mut rand::thread_rng());
+        let mut edges = g
+            .iter_edges()
+            .map(|(i, j)| (i.min(j), i.max(j)))
+            .collect::<Vec<(NodeIndexType, NodeIndexType)>>();
+        edges.sort_unstable();
+        assert_eq!(vec![(0, 1), (0, 2), (1, 2)], edges);
+    }
+
+    #[test]
+    fn test_gnm_reproducible() {
+        let generator = ErdosRenyiGnmGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(100),
+                ParameterValue::PositiveInteger(200),
+            ])
+            .unwrap();
+        let g1: Graph<Undirected> = generator(&mut Pcg32::seed_from_u64(0));
+        let g2: Graph<Undirected> = generator(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(
+            g1.iter_edges()
+                .collect::<Vec<(NodeIndexType, NodeIndexType)>>(),
+            g2.iter_edges()
+                .collect::<Vec<(NodeIndexType, NodeIndexType)>>()
+        );
+    }
 }
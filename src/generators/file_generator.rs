@@ -0,0 +1,297 @@
+use super::{BoxedGenerator, GeneratorFactory};
+use crate::{
+    parsers, Graph, NamedParam, NodeAttributes, NodeIndexType, ParameterType, ParameterValue,
+};
+use anyhow::{anyhow, Context, Result};
+use petgraph::EdgeType;
+use rand::Rng;
+use std::{
+    fs::{self, File},
+    io::BufReader,
+    path::{Path, PathBuf},
+};
+
+/// A factory used to build generators that return a graph read from a file.
+///
+/// The format of the file is given by its extension, and the file is read by the related [parser](crate::parsers):
+///   - `.apx` for the Aspartix format (`arg(a).` and `att(a,b).` statements, comments beginning with `%`);
+///   - `.af` for the DIMACS-like format used at ICCMA'23 (a `p af n` header followed by edges between nodes labelled from 1 to `n`, comments beginning with `#`);
+///   - `.dot` and `.gv` for the Graphviz DOT format;
+///   - `.graphml` for the GraphML format.
+///
+/// The file is read once, when the factory is built, and each call to the generator returns a copy of the graph, node attributes included.
+/// When the file is read as an undirected graph, an edge and its reverse are considered as the same edge.
+///
+/// Such factories can be created by passing `file/path` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str),
+/// where `path` is the path to the file.
+#[derive(Default)]
+pub struct FileGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for FileGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "file"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator returning a graph read from a file.",
+            "The parameter is the path to the file, which format is given by its extension (.apx for Aspartix, .af for ICCMA'23 DIMACS, .dot or .gv for DOT, .graphml for GraphML).",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![ParameterType::String]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let graph = read_graph::<Ty>(Path::new(parameter_values[0].unwrap_str()))
+            .context("while building a file generator")?;
+        Ok(Box::new(move |_| build_graph(&graph)))
+    }
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for FileGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+/// A factory used to build generators that return a graph chosen at random among the files of a directory.
+///
+/// The files of the directory which extension is supported by [`FileGeneratorFactory`] are all read once, when the factory is built;
+/// other files are ignored. Each call to the generator returns a copy of one of these graphs, chosen uniformly at random.
+///
+/// Such factories can be created by passing `file_dir/path` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str),
+/// where `path` is the path to the directory.
+/// The directory must contain at least one supported file.
+#[derive(Default)]
+pub struct FileDirectoryGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for FileDirectoryGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "file_dir"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator returning a graph read from a file chosen at random in a directory.",
+            "The parameter is the path to the directory; only files with a supported extension (.apx, .af, .dot, .gv, .graphml) are considered.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![ParameterType::String]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let context = "while building a directory file generator";
+        let graphs = list_supported_files(Path::new(parameter_values[0].unwrap_str()))
+            .context(context)?
+            .iter()
+            .map(|path| read_graph::<Ty>(path))
+            .collect::<Result<Vec<GraphContent>>>()
+            .context(context)?;
+        Ok(Box::new(move |r| {
+            build_graph(&graphs[r.gen_range(0..graphs.len())])
+        }))
+    }
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for FileDirectoryGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+fn extension(path: &Path) -> Option<&str> {
+    path.extension().and_then(|e| e.to_str())
+}
+
+fn list_supported_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let context = || {
+        format!(
+            r#"while listing the files of directory "{}""#,
+            dir.display()
+        )
+    };
+    let mut paths = vec![];
+    for entry in fs::read_dir(dir).with_context(context)? {
+        let path = entry.with_context(context)?.path();
+        if path.is_file() && extension(&path).is_some_and(parsers::is_supported_extension) {
+            paths.push(path);
+        }
+    }
+    if paths.is_empty() {
+        return Err(anyhow!("no file with a supported extension")).with_context(context);
+    }
+    paths.sort_unstable();
+    Ok(paths)
+}
+
+/// The node attributes and the edges of a graph.
+///
+/// Graphs are stored this way by generators since they must be shared between threads.
+type GraphContent = (Vec<NodeAttributes>, Vec<(NodeIndexType, NodeIndexType)>);
+
+/// Reads a graph from a file, using the parser associated with its extension.
+fn read_graph<Ty>(path: &Path) -> Result<GraphContent>
+where
+    Ty: EdgeType,
+{
+    let context = || format!(r#"while reading a graph from file "{}""#, path.display());
+    let parser = parsers::parser_from_extension::<Ty>(extension(path).unwrap_or_default())
+        .with_context(context)?;
+    let mut reader = BufReader::new(File::open(path).with_context(context)?);
+    let g = parser(&mut reader).with_context(context)?;
+    let node_attributes = (0..g.n_nodes())
+        .map(|i| g.node_attributes(i).clone())
+        .collect();
+    Ok((node_attributes, g.iter_edges().collect()))
+}
+
+fn build_graph<Ty>((node_attributes, edges): &GraphContent) -> Graph<Ty>
+where
+    Ty: EdgeType,
+{
+    let mut g = Graph::with_capacity(node_attributes.len(), edges.len());
+    for (i, attributes) in node_attributes.iter().enumerate() {
+        g.new_node();
+        attributes
+            .iter()
+            .for_each(|(name, value)| g.set_node_attribute(i, name, value.clone()));
+    }
+    edges.iter().for_each(|(i, j)| g.new_edge(*i, *j));
+    g
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{tmp_path, write_tmp_file};
+    use petgraph::{Directed, Undirected};
+    use rand::{rngs::ThreadRng, SeedableRng};
+    use rand_pcg::Pcg32;
+
+    fn edges<Ty>(g: &Graph<Ty>) -> Vec<(NodeIndexType, NodeIndexType)>
+    where
+        Ty: EdgeType,
+    {
+        g.iter_edges().collect()
+    }
+
+    #[test]
+    fn test_aspartix() {
+        let path = write_tmp_file(
+            "file_apx.apx",
+            "% a comment\narg(a).\narg(b).\n\narg(c).\natt(a,b).\natt(b, a).\natt(c,c).\n",
+        );
+        let generator = FileGeneratorFactory
+            .try_with_params(vec![ParameterValue::String(path.clone())])
+            .unwrap();
+        let g: Graph<Directed> = generator(&mut rand::thread_rng());
+        assert_eq!(3, g.n_nodes());
+        assert_eq!(vec![(0, 1), (1, 0), (2, 2)], edges(&g));
+        let g: Graph<Undirected> = FileGeneratorFactory
+            .try_with_params(vec![ParameterValue::String(path.clone())])
+            .unwrap()(&mut rand::thread_rng());
+        assert_eq!(vec![(0, 1), (2, 2)], edges(&g));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_aspartix_undefined_argument() {
+        let path = write_tmp_file("file_apx_undefined.apx", "arg(a).\natt(a,b).\n");
+        let result = FileGeneratorFactory
+            .try_with_params(vec![ParameterValue::String(path.clone())])
+            as Result<BoxedGenerator<Directed, ThreadRng>>;
+        assert!(format!("{:#}", result.err().unwrap()).contains("at line 2"));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_iccma_dimacs() {
+        let path = write_tmp_file("file_dimacs.af", "p af 4\n# a comment\n1 2\n2 3\n");
+        let g: Graph<Directed> = FileGeneratorFactory
+            .try_with_params(vec![ParameterValue::String(path.clone())])
+            .unwrap()(&mut rand::thread_rng());
+        assert_eq!(4, g.n_nodes());
+        assert_eq!(vec![(0, 1), (1, 2)], edges(&g));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_iccma_dimacs_out_of_range() {
+        let path = write_tmp_file("file_dimacs_range.af", "p af 2\n1 3\n");
+        assert!(
+            (FileGeneratorFactory.try_with_params(vec![ParameterValue::String(path.clone())])
+                as Result<BoxedGenerator<Directed, ThreadRng>>)
+                .is_err()
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_unsupported_extension() {
+        let path = write_tmp_file("file_unsupported.txt", "p af 2\n");
+        assert!(
+            (FileGeneratorFactory.try_with_params(vec![ParameterValue::String(path.clone())])
+                as Result<BoxedGenerator<Directed, ThreadRng>>)
+                .is_err()
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_directory() {
+        let dir = tmp_path("file_dir");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("a.af"), "p af 2\n1 2\n").unwrap();
+        std::fs::write(dir.join("b.apx"), "arg(a).\narg(b).\narg(c).\n").unwrap();
+        std::fs::write(dir.join("c.txt"), "not a graph\n").unwrap();
+        let generator = FileDirectoryGeneratorFactory
+            .try_with_params(vec![ParameterValue::String(
+                dir.to_str().unwrap().to_string(),
+            )])
+            .unwrap();
+        let mut rng = Pcg32::seed_from_u64(0);
+        let mut sizes = (0..50)
+            .map(|_| {
+                let g: Graph<Directed> = generator(&mut rng);
+                g.n_nodes()
+            })
+            .collect::<Vec<usize>>();
+        sizes.sort_unstable();
+        sizes.dedup();
+        assert_eq!(vec![2, 3], sizes);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_empty_directory() {
+        let dir = tmp_path("file_dir_empty");
+        std::fs::create_dir_all(&dir).unwrap();
+        assert!(
+            (FileDirectoryGeneratorFactory.try_with_params(vec![ParameterValue::String(
+                dir.to_str().unwrap().to_string(),
+            )]) as Result<BoxedGenerator<Directed, ThreadRng>>)
+                .is_err()
+        );
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
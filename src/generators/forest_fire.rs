@@ -0,0 +1,159 @@
+use super::{BoxedGenerator, GeneratorFactory};
+use crate::{Graph, NamedParam, NodeIndexType, ParameterType, ParameterValue};
+use anyhow::{anyhow, Context, Result};
+use petgraph::Directed;
+use rand::{seq::IteratorRandom, Rng};
+use rand_distr::{Distribution, Geometric};
+use std::collections::{HashSet, VecDeque};
+
+/// A factory used to build generators for graphs following the [forest fire model](https://doi.org/10.1145/1217299.1217301) of Leskovec, Kleinberg and Faloutsos.
+///
+/// Nodes are added one at a time. Each new node `v` first chooses an ambassador node `w` uniformly at random, and links to it.
+/// Then, a fire spreads from `w`: a number `x` is sampled from a geometric distribution with mean `p/(1-p)` and a number `y` from a geometric distribution with mean `q/(1-q)`;
+/// `x` outgoing neighbors and `y` incoming neighbors of `w` that are not burnt yet are chosen at random, `v` links to them, and the fire spreads recursively from each of them.
+/// The graphs produced by this model exhibit heavy-tailed degrees, densification and shrinking diameters.
+///
+/// This generator is only available for directed graphs.
+///
+/// Such factories can be created by passing `forest_fire/n,p,q` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str) where
+///   - `n` is the size of graph to produce;
+///   - `p` is the forward burning probability;
+///   - `q` is the backward burning probability.
+///
+/// Both probabilities must be lower than 1.
+#[derive(Default)]
+pub struct ForestFireGeneratorFactory;
+
+impl<R> NamedParam<BoxedGenerator<Directed, R>> for ForestFireGeneratorFactory
+where
+    R: Rng,
+{
+    fn name(&self) -> &'static str {
+        "forest_fire"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator following the forest fire model.",
+            "First parameter gives the number of nodes, while the second and the third ones give the forward and backward burning probabilities.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![
+            ParameterType::PositiveInteger,
+            ParameterType::Probability,
+            ParameterType::Probability,
+        ]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Directed, R>> {
+        let context = "while building a forest fire generator";
+        let n = parameter_values[0].unwrap_usize();
+        let p = parameter_values[1].unwrap_f64();
+        let q = parameter_values[2].unwrap_f64();
+        if p >= 1. || q >= 1. {
+            return Err(anyhow!("burning probabilities must be lower than 1")).context(context);
+        }
+        let forward = Geometric::new(1. - p).unwrap();
+        let backward = Geometric::new(1. - q).unwrap();
+        Ok(Box::new(move |r| build_graph(n, &forward, &backward, r)))
+    }
+}
+
+impl<R> GeneratorFactory<Directed, R> for ForestFireGeneratorFactory where R: Rng {}
+
+fn build_graph<R>(n: usize, forward: &Geometric, backward: &Geometric, r: &mut R) -> Graph<Directed>
+where
+    R: Rng,
+{
+    let mut out_neighbors: Vec<Vec<NodeIndexType>> = vec![vec![]; n];
+    let mut in_neighbors: Vec<Vec<NodeIndexType>> = vec![vec![]; n];
+    let mut g = Graph::with_capacity(n, 0);
+    (0..n).for_each(|_| g.new_node());
+    for v in 1..n {
+        let ambassador = r.gen_range(0..v);
+        let mut burnt = HashSet::from([ambassador]);
+        let mut burnt_order = vec![ambassador];
+        let mut queue = VecDeque::from([ambassador]);
+        while let Some(w) = queue.pop_front() {
+            let n_forward = forward.sample(r) as usize;
+            let n_backward = backward.sample(r) as usize;
+            let mut newly_burnt = out_neighbors[w]
+                .iter()
+                .filter(|x| !burnt.contains(*x))
+                .copied()
+                .choose_multiple(r, n_forward);
+            newly_burnt.extend(
+                in_neighbors[w]
+                    .iter()
+                    .filter(|x| !burnt.contains(*x) && !newly_burnt.contains(*x))
+                    .copied()
+                    .choose_multiple(r, n_backward),
+            );
+            newly_burnt.into_iter().for_each(|x| {
+                burnt.insert(x);
+                burnt_order.push(x);
+                queue.push_back(x);
+            });
+        }
+        burnt_order.into_iter().for_each(|w| {
+            out_neighbors[v].push(w);
+            in_neighbors[w].push(v);
+            g.new_edge(v, w);
+        });
+    }
+    g
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::ThreadRng, SeedableRng};
+    use rand_pcg::Pcg32;
+
+    #[test]
+    fn test_probability_one() {
+        assert!((ForestFireGeneratorFactory.try_with_params(vec![
+            ParameterValue::PositiveInteger(10),
+            ParameterValue::Probability(1.),
+            ParameterValue::Probability(0.),
+        ]) as Result<BoxedGenerator<Directed, ThreadRng>>)
+            .is_err())
+    }
+
+    #[test]
+    fn test_no_fire() {
+        let g: Graph<Directed> = ForestFireGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(100),
+                ParameterValue::Probability(0.),
+                ParameterValue::Probability(0.),
+            ])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(100, g.n_nodes());
+        assert_eq!(99, g.n_edges());
+        g.iter_edges().for_each(|(v, w)| assert!(w < v));
+    }
+
+    #[test]
+    fn test_simple_graph() {
+        let g: Graph<Directed> = ForestFireGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(500),
+                ParameterValue::Probability(0.37),
+                ParameterValue::Probability(0.32),
+            ])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(500, g.n_nodes());
+        assert!(g.n_edges() > 499);
+        let mut edges = HashSet::new();
+        g.iter_edges().for_each(|(v, w)| {
+            assert!(w < v);
+            assert!(edges.insert((v, w)));
+        });
+    }
+}
@@ -0,0 +1,230 @@
+use super::{BoxedGenerator, GeneratorFactory};
+use crate::{Graph, NamedParam, ParameterType, ParameterValue};
+use anyhow::{anyhow, Context, Result};
+use petgraph::EdgeType;
+use rand::Rng;
+
+/// A factory used to build generators for grid graphs (lattices), in any dimension.
+///
+/// Nodes are indexed in row-major order, the first dimension being the one which varies the fastest:
+/// in a `3:2` grid, the first row contains nodes 0, 1 and 2 while the second contains nodes 3, 4 and 5.
+///
+/// In directed graphs generated by this objects, edges go from nodes to their successors in each dimension.
+///
+/// Such factories can be created by passing `grid/s1:s2:...:sk` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str),
+/// where `s1:s2:...:sk` are the sizes of the grid in each dimension (eg. `10:10` for a 2D grid and `5:5:5` for a 3D one).
+/// Sizes must be higher than zero.
+#[derive(Default)]
+pub struct GridGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for GridGeneratorFactory
+where
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "grid"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator producing a grid graph.",
+            "The first parameter gives the size of the grid in each dimension, separated by colons (eg. 10:10 or 5:5:5).",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![ParameterType::PositiveIntegerList]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let dimensions =
+            read_dimensions(&parameter_values[0]).context("while building a grid generator")?;
+        Ok(Box::new(move |_| build_lattice(&dimensions, false)))
+    }
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for GridGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+/// A factory used to build generators for torus graphs, that is grid graphs with wraparound edges, in any dimension.
+///
+/// Nodes are indexed like in [`GridGeneratorFactory`].
+/// In addition to the edges of the grid, the last node of each row (in each dimension) is linked to the first one of the same row.
+/// These wraparound edges are only added for dimensions with a size of at least 3, in order to prevent self-loops and multi-edges.
+///
+/// In directed graphs generated by this objects, edges go from nodes to their successors in each dimension, and from the last nodes of the rows to the first ones.
+///
+/// Such factories can be created by passing `torus/s1:s2:...:sk` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str),
+/// where `s1:s2:...:sk` are the sizes of the torus in each dimension (eg. `10:10` for a 2D torus and `5:5:5` for a 3D one).
+/// Sizes must be higher than zero.
+#[derive(Default)]
+pub struct TorusGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for TorusGeneratorFactory
+where
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "torus"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator producing a torus graph, that is a grid with wraparound edges.",
+            "The first parameter gives the size of the torus in each dimension, separated by colons (eg. 10:10 or 5:5:5).",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![ParameterType::PositiveIntegerList]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let dimensions =
+            read_dimensions(&parameter_values[0]).context("while building a torus generator")?;
+        Ok(Box::new(move |_| build_lattice(&dimensions, true)))
+    }
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for TorusGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+pub(crate) fn read_dimensions(parameter_value: &ParameterValue) -> Result<Vec<usize>> {
+    let dimensions = parameter_value.unwrap_usize_list().to_vec();
+    if dimensions.contains(&0) {
+        return Err(anyhow!("sizes must be higher than 0"));
+    }
+    if dimensions
+        .iter()
+        .try_fold(1_usize, |acc, s| acc.checked_mul(*s))
+        .is_none()
+    {
+        return Err(anyhow!("too many nodes"));
+    }
+    Ok(dimensions)
+}
+
+pub(crate) fn build_lattice<Ty>(dimensions: &[usize], wraparound: bool) -> Graph<Ty>
+where
+    Ty: EdgeType,
+{
+    let n = dimensions.iter().product::<usize>();
+    let mut g = Graph::with_capacity(n, n * dimensions.len());
+    (0..n).for_each(|_| g.new_node());
+    let strides = dimensions
+        .iter()
+        .scan(1, |stride, s| {
+            let current = *stride;
+            *stride *= s;
+            Some(current)
+        })
+        .collect::<Vec<usize>>();
+    (0..n).for_each(|i| {
+        dimensions
+            .iter()
+            .zip(strides.iter())
+            .for_each(|(size, stride)| {
+                let coordinate = (i / stride) % size;
+                if coordinate + 1 < *size {
+                    g.new_edge(i, i + stride);
+                } else if wraparound && *size >= 3 {
+                    g.new_edge(i, i - coordinate * stride);
+                }
+            })
+    });
+    g
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NodeIndexType;
+    use petgraph::Directed;
+    use rand::rngs::ThreadRng;
+
+    #[test]
+    fn test_zero_size() {
+        assert!((GridGeneratorFactory
+            .try_with_params(vec![ParameterValue::PositiveIntegerList(vec![2, 0])])
+            as Result<BoxedGenerator<Directed, ThreadRng>>)
+            .is_err())
+    }
+
+    #[test]
+    fn test_grid_2d() {
+        let mut rng = rand::thread_rng();
+        let g: Graph<Directed> = GridGeneratorFactory
+            .try_with_params(vec![ParameterValue::PositiveIntegerList(vec![3, 2])])
+            .unwrap()(&mut rng);
+        assert_eq!(6, g.n_nodes());
+        let mut edges = g
+            .iter_edges()
+            .collect::<Vec<(NodeIndexType, NodeIndexType)>>();
+        edges.sort_unstable();
+        assert_eq!(
+            vec![(0, 1), (0, 3), (1, 2), (1, 4), (2, 5), (3, 4), (4, 5)],
+            edges
+        );
+    }
+
+    #[test]
+    fn test_grid_3d() {
+        let mut rng = rand::thread_rng();
+        let g: Graph<Directed> = GridGeneratorFactory
+            .try_with_params(vec![ParameterValue::PositiveIntegerList(vec![2, 2, 2])])
+            .unwrap()(&mut rng);
+        assert_eq!(8, g.n_nodes());
+        assert_eq!(12, g.n_edges());
+    }
+
+    #[test]
+    fn test_torus_2d() {
+        let mut rng = rand::thread_rng();
+        let g: Graph<Directed> = TorusGeneratorFactory
+            .try_with_params(vec![ParameterValue::PositiveIntegerList(vec![3, 2])])
+            .unwrap()(&mut rng);
+        assert_eq!(6, g.n_nodes());
+        let mut edges = g
+            .iter_edges()
+            .collect::<Vec<(NodeIndexType, NodeIndexType)>>();
+        edges.sort_unstable();
+        assert_eq!(
+            vec![
+                (0, 1),
+                (0, 3),
+                (1, 2),
+                (1, 4),
+                (2, 0),
+                (2, 5),
+                (3, 4),
+                (4, 5),
+                (5, 3)
+            ],
+            edges
+        );
+    }
+
+    #[test]
+    fn test_torus_3d() {
+        let mut rng = rand::thread_rng();
+        let g: Graph<Directed> = TorusGeneratorFactory
+            .try_with_params(vec![ParameterValue::PositiveIntegerList(vec![3, 3, 3])])
+            .unwrap()(&mut rng);
+        assert_eq!(27, g.n_nodes());
+        assert_eq!(81, g.n_edges());
+    }
+}
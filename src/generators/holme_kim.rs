@@ -0,0 +1,178 @@
+use super::{BoxedGenerator, GeneratorFactory};
+use crate::{Graph, NamedParam, NodeIndexType, ParameterType, ParameterValue};
+use anyhow::{anyhow, Context, Result};
+use petgraph::EdgeType;
+use rand::{seq::SliceRandom, Rng};
+
+/// A factory used to build generators for [Holme-Kim](https://doi.org/10.1103/PhysRevE.65.026107) graphs, that is scale-free graphs with a tunable clustering.
+///
+/// The process starts with `m` isolated nodes. Then, each new node is linked to `m` existing nodes.
+/// The first one is chosen by preferential attachment (with a probability proportional to its degree).
+/// Each of the following ones is chosen, with probability `p`, among the neighbors of the last node chosen by preferential attachment (triad formation step),
+/// or by preferential attachment otherwise.
+/// In case no neighbor is available for the triad formation step, a preferential attachment step is made instead.
+///
+/// In directed graphs generated with this object, edge sources are the new nodes and targets the existing ones.
+///
+/// Such factories can be created by passing `hk/n,m,p` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str) where
+///   - `n` is the size of graph to produce;
+///   - `m` is the number of edges added with each new node;
+///   - `p` is the probability of triad formation steps.
+///
+/// Parameter `m` must be higher than zero and lower than `n`.
+#[derive(Default)]
+pub struct HolmeKimGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for HolmeKimGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "hk"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator following the Holme-Kim model (preferential attachment with triad formation).",
+            "First parameter gives the number of nodes of the graph, the second one the number of edges added with each node, and the third one the probability of triad formation.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![
+            ParameterType::PositiveInteger,
+            ParameterType::PositiveInteger,
+            ParameterType::Probability,
+        ]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let n = parameter_values[0].unwrap_usize();
+        let m = parameter_values[1].unwrap_usize();
+        let p = parameter_values[2].unwrap_f64();
+        if m == 0 || m >= n {
+            return Err(anyhow!(
+                r#"second parameter ("m") must be higher than 0 and lower than the first one ("n")"#
+            ))
+            .context("while building a Holme-Kim generator");
+        }
+        Ok(Box::new(move |r| build_graph(n, m, p, r)))
+    }
+}
+
+fn build_graph<Ty, R>(n: usize, m: usize, p: f64, r: &mut R) -> Graph<Ty>
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    let mut neighbors: Vec<Vec<NodeIndexType>> = vec![vec![]; n];
+    let mut repeated_nodes = (0..m).collect::<Vec<NodeIndexType>>();
+    let mut g = Graph::with_capacity(n, (n - m) * m);
+    (0..n).for_each(|_| g.new_node());
+    for source in m..n {
+        let mut pa_target: Option<NodeIndexType> = None;
+        while neighbors[source].len() < m {
+            let triad_candidates = match pa_target {
+                Some(t) if r.gen_bool(p) => neighbors[t]
+                    .iter()
+                    .copied()
+                    .filter(|i| *i != source && !neighbors[source].contains(i))
+                    .collect(),
+                _ => vec![],
+            };
+            let target = match triad_candidates.choose(r) {
+                Some(t) => *t,
+                None => {
+                    let t = loop {
+                        let candidate = *repeated_nodes.choose(r).unwrap();
+                        if !neighbors[source].contains(&candidate) {
+                            break candidate;
+                        }
+                    };
+                    pa_target = Some(t);
+                    t
+                }
+            };
+            neighbors[source].push(target);
+            neighbors[target].push(source);
+            g.new_edge(source, target);
+        }
+        repeated_nodes.extend_from_slice(&neighbors[source]);
+        repeated_nodes.extend(std::iter::repeat_n(source, m));
+    }
+    g
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for HolmeKimGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::{Directed, Undirected};
+    use rand::{rngs::ThreadRng, SeedableRng};
+    use rand_pcg::Pcg32;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_m_is_zero() {
+        assert!((HolmeKimGeneratorFactory.try_with_params(vec![
+            ParameterValue::PositiveInteger(2),
+            ParameterValue::PositiveInteger(0),
+            ParameterValue::Probability(0.5),
+        ]) as Result<BoxedGenerator<Directed, ThreadRng>>)
+            .is_err())
+    }
+
+    #[test]
+    fn test_n_is_not_higher_than_m() {
+        assert!((HolmeKimGeneratorFactory.try_with_params(vec![
+            ParameterValue::PositiveInteger(2),
+            ParameterValue::PositiveInteger(2),
+            ParameterValue::Probability(0.5),
+        ]) as Result<BoxedGenerator<Directed, ThreadRng>>)
+            .is_err())
+    }
+
+    #[test]
+    fn test_holme_kim_star() {
+        let g: Graph<Directed> = HolmeKimGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(4),
+                ParameterValue::PositiveInteger(3),
+                ParameterValue::Probability(0.5),
+            ])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        let mut edges = g
+            .iter_edges()
+            .collect::<Vec<(NodeIndexType, NodeIndexType)>>();
+        edges.sort_unstable();
+        assert_eq!(vec![(3, 0), (3, 1), (3, 2)], edges);
+    }
+
+    #[test]
+    fn test_holme_kim_simple_graph() {
+        let g: Graph<Undirected> = HolmeKimGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(500),
+                ParameterValue::PositiveInteger(3),
+                ParameterValue::Probability(0.8),
+            ])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(500, g.n_nodes());
+        assert_eq!(497 * 3, g.n_edges());
+        let mut edges = HashSet::new();
+        g.iter_edges().for_each(|(i, j)| {
+            assert_ne!(i, j);
+            assert!(edges.insert((i.min(j), i.max(j))));
+        });
+    }
+}
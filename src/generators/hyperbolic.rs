@@ -0,0 +1,388 @@
+use super::{BoxedGenerator, GeneratorFactory, POSITION_ATTRIBUTE};
+use crate::{Graph, NamedParam, NodeAttributeValue, NodeIndexType, ParameterType, ParameterValue};
+use anyhow::{anyhow, Context, Result};
+use petgraph::EdgeType;
+use rand::Rng;
+use std::f64::consts::PI;
+
+/// A factory used to build generators for [hyperbolic random graphs](https://doi.org/10.1103/PhysRevE.82.036106).
+///
+/// Nodes are placed at random in a hyperbolic disk of radius `R`: angles are uniform, while radii are distributed so that the degrees follow a power law of exponent `gamma`.
+/// At temperature `T = 0`, two nodes are linked if their hyperbolic distance is at most `R`;
+/// at temperature `T > 0`, they are linked with probability `1 / (1 + exp((d - R) / (2T)))`, where `d` is their distance.
+/// The radius `R` is computed from the other parameters so that the average degree is approximately `k`.
+///
+/// The disk is split into bands, in which nodes are sorted by angle.
+/// For each node, the candidate neighbors of each band are explored in both angular directions,
+/// skipping nodes according to an upper bound of the connection probability, which makes the expected running time close to linear in the number of nodes and edges.
+///
+/// In directed graphs generated by this objects, linked nodes are connected by two edges, one in each direction.
+///
+/// The native coordinates `(r cos(θ), r sin(θ))` of each node are stored as its `pos` attribute.
+///
+/// Such factories can be created by passing `hyperbolic/n,k,gamma,T` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str) where
+///   - `n` is the size of graph to produce;
+///   - `k` is the expected average degree;
+///   - `gamma` is the exponent of the degree distribution;
+///   - `T` is the temperature.
+///
+/// Parameter `k` must be higher than zero, `gamma` must be higher than 2 and `T` must be lower than 1.
+#[derive(Default)]
+pub struct HyperbolicGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for HyperbolicGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "hyperbolic"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator producing hyperbolic random graphs.",
+            "First parameter gives the number of nodes, the second one the expected average degree, the third one the exponent of the degree distribution and the last one the temperature.",
+            "Node coordinates are exported as the \"pos\" attribute.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![
+            ParameterType::PositiveInteger,
+            ParameterType::PositiveFloat,
+            ParameterType::PositiveFloat,
+            ParameterType::Probability,
+        ]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let context = "while building a hyperbolic random graph generator";
+        let n = parameter_values[0].unwrap_usize();
+        let k = parameter_values[1].unwrap_f64();
+        let gamma = parameter_values[2].unwrap_f64();
+        let temperature = parameter_values[3].unwrap_f64();
+        if k <= 0. {
+            return Err(anyhow!(r#"second parameter ("k") must be higher than 0"#))
+                .context(context);
+        }
+        if gamma <= 2. {
+            return Err(anyhow!(
+                r#"third parameter ("gamma") must be higher than 2"#
+            ))
+            .context(context);
+        }
+        if temperature >= 1. {
+            return Err(anyhow!(r#"fourth parameter ("T") must be lower than 1"#)).context(context);
+        }
+        let alpha = (gamma - 1.) / 2.;
+        let radius = disk_radius(n, k, alpha, temperature);
+        if n > 1 && radius <= 0. {
+            return Err(anyhow!(
+                "the average degree is too high for this number of nodes"
+            ))
+            .context(context);
+        }
+        let model = HyperbolicModel {
+            alpha,
+            radius,
+            temperature,
+        };
+        Ok(Box::new(move |r| model.build_graph(n, r)))
+    }
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for HyperbolicGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+/// Computes the radius of the disk giving approximately the expected average degree, following Krioukov et al.
+fn disk_radius(n: usize, k: f64, alpha: f64, temperature: f64) -> f64 {
+    let xi = alpha / (alpha - 0.5);
+    let temperature_factor = if temperature == 0. {
+        2. / PI
+    } else {
+        2. * temperature / (PI * temperature).sin()
+    };
+    let nu = k / (xi * xi * temperature_factor);
+    2. * (n as f64 / nu).ln()
+}
+
+struct HyperbolicModel {
+    alpha: f64,
+    radius: f64,
+    temperature: f64,
+}
+
+/// A node of a band, given by its index, its radius and its angle.
+#[derive(Clone, Copy)]
+struct BandNode {
+    index: NodeIndexType,
+    radius: f64,
+    angle: f64,
+}
+
+impl HyperbolicModel {
+    fn build_graph<Ty, R>(&self, n: usize, r: &mut R) -> Graph<Ty>
+    where
+        R: Rng,
+        Ty: EdgeType,
+    {
+        let mut g = Graph::with_capacity(n, 0);
+        (0..n).for_each(|_| g.new_node());
+        if n < 2 {
+            return g;
+        }
+        let cosh_max = (self.alpha * self.radius).cosh() - 1.;
+        let nodes = (0..n)
+            .map(|index| BandNode {
+                index,
+                radius: (1. + r.gen::<f64>() * cosh_max).acosh() / self.alpha,
+                angle: r.gen::<f64>() * 2. * PI,
+            })
+            .collect::<Vec<BandNode>>();
+        let n_bands = self.radius.ceil().max(1.) as usize;
+        let band_width = self.radius / n_bands as f64;
+        let band_of = |node: &BandNode| ((node.radius / band_width) as usize).min(n_bands - 1);
+        let mut bands: Vec<Vec<BandNode>> = vec![vec![]; n_bands];
+        nodes
+            .iter()
+            .for_each(|node| bands[band_of(node)].push(*node));
+        bands
+            .iter_mut()
+            .for_each(|band| band.sort_by(|a, b| a.angle.total_cmp(&b.angle)));
+        let mut edges = vec![];
+        nodes.iter().for_each(|u| {
+            (band_of(u)..n_bands).for_each(|j| {
+                let min_radius = (j as f64 * band_width).max(u.radius);
+                self.sample_band_neighbors(u, &bands[j], min_radius, r)
+                    .into_iter()
+                    .for_each(|v| edges.push((u.index.min(v), u.index.max(v))));
+            })
+        });
+        edges.sort_unstable();
+        edges.into_iter().for_each(|(i, j)| {
+            g.new_edge(i, j);
+            if Ty::is_directed() {
+                g.new_edge(j, i);
+            }
+        });
+        nodes.into_iter().for_each(|node| {
+            g.set_node_attribute(
+                node.index,
+                POSITION_ATTRIBUTE,
+                NodeAttributeValue::FloatList(vec![
+                    node.radius * node.angle.cos(),
+                    node.radius * node.angle.sin(),
+                ]),
+            )
+        });
+        g
+    }
+
+    /// Samples the neighbors of a node in a band, considering only the nodes that are farther from the center than the node itself.
+    ///
+    /// Nodes of the band are explored in both angular directions; `min_radius` is a lower bound of the radii of the candidates.
+    fn sample_band_neighbors<R>(
+        &self,
+        u: &BandNode,
+        band: &[BandNode],
+        min_radius: f64,
+        r: &mut R,
+    ) -> Vec<NodeIndexType>
+    where
+        R: Rng,
+    {
+        let mut neighbors = vec![];
+        if band.is_empty() {
+            return neighbors;
+        }
+        let start = band.partition_point(|v| v.angle < u.angle);
+        let angular_distance = |v: &BandNode| {
+            let d = (v.angle - u.angle).abs();
+            d.min(2. * PI - d)
+        };
+        let n_clockwise = if u.angle + PI < 2. * PI {
+            band.partition_point(|v| v.angle < u.angle + PI) - start
+        } else {
+            band.len() - start + band.partition_point(|v| v.angle < u.angle - PI)
+        };
+        let clockwise = |k: usize| (start + k) % band.len();
+        let counterclockwise = |k: usize| (start + 2 * band.len() - 1 - k) % band.len();
+        let sides: [(usize, &dyn Fn(usize) -> usize); 2] = [
+            (n_clockwise, &clockwise),
+            (band.len() - n_clockwise, &counterclockwise),
+        ];
+        let is_candidate =
+            |v: &BandNode| v.radius > u.radius || (v.radius == u.radius && v.index > u.index);
+        for (side_len, side) in sides {
+            if side_len == 0 {
+                continue;
+            }
+            let bound = |k: usize| {
+                self.connection_probability(self.distance(
+                    u.radius,
+                    min_radius,
+                    angular_distance(&band[side(k)]),
+                ))
+            };
+            let mut k = 0;
+            let mut p = bound(0);
+            while k < side_len && p > 0. {
+                if p < 1. {
+                    let x = 1. - r.gen::<f64>();
+                    k = k.saturating_add((x.ln() / (1. - p).ln()).floor() as usize);
+                }
+                if k < side_len {
+                    let v = &band[side(k)];
+                    if is_candidate(v) {
+                        let q = self.connection_probability(self.distance(
+                            u.radius,
+                            v.radius,
+                            angular_distance(v),
+                        ));
+                        if r.gen::<f64>() * p < q {
+                            neighbors.push(v.index);
+                        }
+                    }
+                    p = bound(k);
+                    k += 1;
+                }
+            }
+        }
+        neighbors
+    }
+
+    fn distance(&self, r1: f64, r2: f64, angular_distance: f64) -> f64 {
+        (r1.cosh() * r2.cosh() - r1.sinh() * r2.sinh() * angular_distance.cos())
+            .max(1.)
+            .acosh()
+    }
+
+    fn connection_probability(&self, distance: f64) -> f64 {
+        if self.temperature == 0. {
+            if distance <= self.radius {
+                1.
+            } else {
+                0.
+            }
+        } else {
+            1. / (1. + ((distance - self.radius) / (2. * self.temperature)).exp())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::{Directed, Undirected};
+    use rand::{rngs::ThreadRng, SeedableRng};
+    use rand_pcg::Pcg32;
+    use std::collections::HashSet;
+
+    fn params(n: usize, k: f64, gamma: f64, temperature: f64) -> Vec<ParameterValue> {
+        vec![
+            ParameterValue::PositiveInteger(n),
+            ParameterValue::PositiveFloat(k),
+            ParameterValue::PositiveFloat(gamma),
+            ParameterValue::Probability(temperature),
+        ]
+    }
+
+    fn positions<Ty>(g: &Graph<Ty>) -> Vec<(f64, f64)>
+    where
+        Ty: EdgeType,
+    {
+        (0..g.n_nodes())
+            .map(|i| match g.node_attributes(i).get(POSITION_ATTRIBUTE) {
+                Some(NodeAttributeValue::FloatList(p)) => {
+                    ((p[0] * p[0] + p[1] * p[1]).sqrt(), p[1].atan2(p[0]))
+                }
+                _ => panic!(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_wrong_gamma() {
+        assert!(
+            (HyperbolicGeneratorFactory.try_with_params(params(100, 5., 2., 0.))
+                as Result<BoxedGenerator<Undirected, ThreadRng>>)
+                .is_err()
+        )
+    }
+
+    #[test]
+    fn test_wrong_temperature() {
+        assert!(
+            (HyperbolicGeneratorFactory.try_with_params(params(100, 5., 2.5, 1.))
+                as Result<BoxedGenerator<Undirected, ThreadRng>>)
+                .is_err()
+        )
+    }
+
+    #[test]
+    fn test_threshold_same_as_naive() {
+        let g: Graph<Undirected> = HyperbolicGeneratorFactory
+            .try_with_params(params(500, 6., 2.5, 0.))
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(500, g.n_nodes());
+        let alpha = 0.75;
+        let model = HyperbolicModel {
+            alpha,
+            radius: disk_radius(500, 6., alpha, 0.),
+            temperature: 0.,
+        };
+        let positions = positions(&g);
+        let expected = (0..500)
+            .flat_map(|i| (i + 1..500).map(move |j| (i, j)))
+            .filter(|(i, j)| {
+                let (r1, a1) = positions[*i];
+                let (r2, a2) = positions[*j];
+                let d = model.distance(r1, r2, (a1 - a2).abs());
+                // ignore pairs too close to the threshold to be robust to rounding errors
+                assert!((d - model.radius).abs() > 1e-6);
+                d <= model.radius
+            })
+            .collect::<Vec<(NodeIndexType, NodeIndexType)>>();
+        assert_eq!(
+            expected,
+            g.iter_edges()
+                .collect::<Vec<(NodeIndexType, NodeIndexType)>>()
+        );
+    }
+
+    #[test]
+    fn test_average_degree() {
+        for temperature in [0., 0.5] {
+            let g: Graph<Undirected> = HyperbolicGeneratorFactory
+                .try_with_params(params(5000, 10., 3., temperature))
+                .unwrap()(&mut Pcg32::seed_from_u64(0));
+            let average_degree = 2. * g.n_edges() as f64 / 5000.;
+            assert!(
+                (5. ..20.).contains(&average_degree),
+                "average degree is {}",
+                average_degree
+            );
+            let mut edges = HashSet::new();
+            g.iter_edges().for_each(|(i, j)| {
+                assert_ne!(i, j);
+                assert!(edges.insert((i, j)));
+            });
+        }
+    }
+
+    #[test]
+    fn test_directed() {
+        let g: Graph<Directed> = HyperbolicGeneratorFactory
+            .try_with_params(params(100, 5., 2.5, 0.3))
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(0, g.n_edges() % 2);
+    }
+}
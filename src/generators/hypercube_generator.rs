@@ -0,0 +1,123 @@
+use super::{BoxedGenerator, GeneratorFactory};
+use crate::{Graph, NamedParam, ParameterType, ParameterValue};
+use anyhow::{anyhow, Context, Result};
+use petgraph::EdgeType;
+use rand::Rng;
+
+/// A factory used to build generators for [hypercube graphs](https://en.wikipedia.org/wiki/Hypercube_graph).
+///
+/// The hypercube of dimension `d` has `2^d` nodes; two nodes are linked if the binary representations of their indices differ by exactly one bit.
+///
+/// In directed graphs generated by this objects, edges go from nodes to the ones with a higher index.
+///
+/// Such factories can be created by passing `hypercube/d` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str),
+/// where `d` is the dimension of the hypercube.
+#[derive(Default)]
+pub struct HypercubeGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for HypercubeGeneratorFactory
+where
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "hypercube"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator producing a hypercube graph.",
+            "The first parameter gives the dimension of the hypercube.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![ParameterType::PositiveInteger]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let d = parameter_values[0].unwrap_usize();
+        if d >= usize::BITS as usize - 1 {
+            return Err(anyhow!("the dimension is too high"))
+                .context("while building a hypercube generator");
+        }
+        Ok(Box::new(move |_| {
+            let n = 1 << d;
+            let mut g = Graph::with_capacity(n, d * n / 2);
+            (0..n).for_each(|_| g.new_node());
+            (0..n).for_each(|i| {
+                (0..d)
+                    .map(|b| 1 << b)
+                    .filter(|bit| i & bit == 0)
+                    .for_each(|bit| g.new_edge(i, i | bit))
+            });
+            g
+        }))
+    }
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for HypercubeGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NodeIndexType;
+    use petgraph::{Directed, Undirected};
+    use rand::rngs::ThreadRng;
+
+    #[test]
+    fn test_dimension_too_high() {
+        assert!((HypercubeGeneratorFactory
+            .try_with_params(vec![ParameterValue::PositiveInteger(usize::BITS as usize)])
+            as Result<BoxedGenerator<Directed, ThreadRng>>)
+            .is_err())
+    }
+
+    #[test]
+    fn test_hypercube_of_zero() {
+        let mut rng = rand::thread_rng();
+        let g: Graph<Directed> = HypercubeGeneratorFactory
+            .try_with_params(vec![ParameterValue::PositiveInteger(0)])
+            .unwrap()(&mut rng);
+        assert_eq!(1, g.n_nodes());
+        assert_eq!(0, g.n_edges());
+    }
+
+    #[test]
+    fn test_square() {
+        let mut rng = rand::thread_rng();
+        let g: Graph<Directed> = HypercubeGeneratorFactory
+            .try_with_params(vec![ParameterValue::PositiveInteger(2)])
+            .unwrap()(&mut rng);
+        assert_eq!(4, g.n_nodes());
+        let mut edges = g
+            .iter_edges()
+            .collect::<Vec<(NodeIndexType, NodeIndexType)>>();
+        edges.sort_unstable();
+        assert_eq!(vec![(0, 1), (0, 2), (1, 3), (2, 3)], edges);
+    }
+
+    #[test]
+    fn test_degrees() {
+        let mut rng = rand::thread_rng();
+        let g: Graph<Undirected> = HypercubeGeneratorFactory
+            .try_with_params(vec![ParameterValue::PositiveInteger(5)])
+            .unwrap()(&mut rng);
+        assert_eq!(32, g.n_nodes());
+        assert_eq!(80, g.n_edges());
+        let mut degrees = vec![0; 32];
+        g.iter_edges().for_each(|(i, j)| {
+            assert_eq!(1, (i ^ j).count_ones());
+            degrees[i] += 1;
+            degrees[j] += 1;
+        });
+        assert_eq!(vec![5; 32], degrees);
+    }
+}
@@ -0,0 +1,375 @@
+use super::{
+    grid_generator::{build_lattice, read_dimensions},
+    BoxedGenerator, GeneratorFactory, POSITION_ATTRIBUTE,
+};
+use crate::{Graph, NamedParam, NodeAttributeValue, NodeIndexType, ParameterType, ParameterValue};
+use anyhow::{Context, Result};
+use petgraph::EdgeType;
+use rand::Rng;
+use std::collections::HashSet;
+
+/// A factory used to build generators for [Kleinberg's navigable small-world graphs](https://doi.org/10.1145/335305.335325).
+///
+/// Graphs are built from a grid (see [`GridGeneratorFactory`](crate::generators::GridGeneratorFactory)), in which each node is linked to its lattice neighbors.
+/// Then, each node `u` gets `q` long-range contacts, each of them being a node `v` chosen with a probability proportional to `d(u,v)^-r`,
+/// where `d` is the Manhattan distance on the grid.
+/// Long-range contacts that duplicate an existing edge are discarded.
+///
+/// Contacts are drawn among the nodes of the grid only, without any rejection: their coordinates are drawn one dimension after the other,
+/// each position being weighted by the sum of the weights of the nodes of the grid that remain reachable from it.
+///
+/// In directed graphs generated by this objects, lattice neighbors are connected in both directions, while long-range links go from the nodes to their contacts.
+///
+/// The coordinates of each node on the grid are stored as its `pos` attribute.
+///
+/// Such factories can be created by passing `kleinberg/s1:s2:...:sk,q,r` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str) where
+///   - `s1:s2:...:sk` are the sizes of the grid in each dimension (eg. `100:100` for a 2D grid);
+///   - `q` is the number of long-range contacts of each node;
+///   - `r` is the clustering exponent.
+///
+/// Sizes must be higher than zero.
+/// Kleinberg showed that decentralized routing is efficient when `r` is equal to the number of dimensions.
+#[derive(Default)]
+pub struct KleinbergGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for KleinbergGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "kleinberg"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator following Kleinberg's navigable small-world model.",
+            "First parameter gives the size of the grid in each dimension, separated by colons (eg. 100:100).",
+            "The second parameter gives the number of long-range contacts of each node, and the third one the exponent of the distance.",
+            "Grid coordinates are exported as the \"pos\" attribute.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![
+            ParameterType::PositiveIntegerList,
+            ParameterType::PositiveInteger,
+            ParameterType::PositiveFloat,
+        ]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let context = "while building a Kleinberg generator";
+        let dimensions = read_dimensions(&parameter_values[0]).context(context)?;
+        let n_contacts = parameter_values[1].unwrap_usize();
+        let exponent = parameter_values[2].unwrap_f64();
+        let model = KleinbergModel::new(dimensions, n_contacts, exponent);
+        Ok(Box::new(move |r| model.build_graph(r)))
+    }
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for KleinbergGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+struct KleinbergModel {
+    dimensions: Vec<usize>,
+    n_contacts: usize,
+    /// The sums of the weights of the distances, from each distance to the farthest one, followed by a zero
+    distance_sums: Vec<f64>,
+}
+
+impl KleinbergModel {
+    fn new(dimensions: Vec<usize>, n_contacts: usize, exponent: f64) -> Self {
+        let max_distance = dimensions.iter().map(|s| s - 1).sum::<usize>();
+        let mut distance_sums = vec![0.; max_distance + 2];
+        (1..=max_distance).rev().for_each(|d| {
+            distance_sums[d] = distance_sums[d + 1] + (d as f64).powf(-exponent);
+        });
+        distance_sums[0] = distance_sums[1];
+        Self {
+            dimensions,
+            n_contacts,
+            distance_sums,
+        }
+    }
+
+    fn build_graph<Ty, R>(&self, r: &mut R) -> Graph<Ty>
+    where
+        R: Rng,
+        Ty: EdgeType,
+    {
+        let edge_key = |i: NodeIndexType, j: NodeIndexType| {
+            if Ty::is_directed() {
+                (i, j)
+            } else {
+                (i.min(j), i.max(j))
+            }
+        };
+        let mut g: Graph<Ty> = build_lattice(&self.dimensions, false);
+        let lattice_edges = g
+            .iter_edges()
+            .collect::<Vec<(NodeIndexType, NodeIndexType)>>();
+        if Ty::is_directed() {
+            lattice_edges.iter().for_each(|(i, j)| g.new_edge(*j, *i));
+        }
+        let mut edges = g
+            .iter_edges()
+            .map(|(i, j)| edge_key(i, j))
+            .collect::<HashSet<(NodeIndexType, NodeIndexType)>>();
+        let all_coordinates = (0..g.n_nodes())
+            .map(|i| self.coordinates(i))
+            .collect::<Vec<Vec<usize>>>();
+        if g.n_nodes() > 1 && self.n_contacts > 0 {
+            let n_dimensions = self.dimensions.len();
+            let mut weight_sums = vec![vec![]; n_dimensions];
+            weight_sums[n_dimensions - 1] = self.distance_sums.clone();
+            all_coordinates
+                .iter()
+                .enumerate()
+                .for_each(|(i, coordinates)| {
+                    // the sums for a dimension only depend on the coordinates of the next ones, which seldom change
+                    let highest_change = if i == 0 {
+                        n_dimensions - 1
+                    } else {
+                        (0..n_dimensions)
+                            .rev()
+                            .find(|k| coordinates[*k] != all_coordinates[i - 1][*k])
+                            .unwrap()
+                    };
+                    (0..highest_change).rev().for_each(|k| {
+                        weight_sums[k] = add_dimension(
+                            &weight_sums[k + 1],
+                            coordinates[k + 1],
+                            self.dimensions[k + 1],
+                        );
+                    });
+                    (0..self.n_contacts).for_each(|_| {
+                        let j = self.sample_contact(coordinates, &weight_sums, r);
+                        if edges.insert(edge_key(i, j)) {
+                            g.new_edge(i, j);
+                        }
+                    })
+                });
+        }
+        all_coordinates
+            .into_iter()
+            .enumerate()
+            .for_each(|(i, coordinates)| {
+                g.set_node_attribute(
+                    i,
+                    POSITION_ATTRIBUTE,
+                    NodeAttributeValue::FloatList(
+                        coordinates.into_iter().map(|c| c as f64).collect(),
+                    ),
+                )
+            });
+        g
+    }
+
+    fn coordinates(&self, mut index: NodeIndexType) -> Vec<usize> {
+        self.dimensions
+            .iter()
+            .map(|size| {
+                let c = index % size;
+                index /= size;
+                c
+            })
+            .collect()
+    }
+
+    /// Samples a long-range contact of the node with the given coordinates.
+    ///
+    /// The coordinates of the contact are drawn one dimension after the other, by a binary search on the cumulative weights of the positions.
+    /// The `k`-th vector of `weight_sums` contains the suffix sums of the weights for the dimensions after `k` (see [`cumulative_weight`]).
+    fn sample_contact<R>(
+        &self,
+        coordinates: &[usize],
+        weight_sums: &[Vec<f64>],
+        r: &mut R,
+    ) -> NodeIndexType
+    where
+        R: Rng,
+    {
+        let mut distance = 0;
+        let mut index = 0;
+        let mut stride = 1;
+        for (k, (c, size)) in coordinates.iter().zip(self.dimensions.iter()).enumerate() {
+            let cumulative_weight = |x| cumulative_weight(&weight_sums[k], distance, *c, x);
+            let u = r.gen_range(0. ..cumulative_weight(size - 1));
+            let (mut low, mut high) = (0, size - 1);
+            while low < high {
+                let mid = (low + high) / 2;
+                if cumulative_weight(mid) > u {
+                    high = mid;
+                } else {
+                    low = mid + 1;
+                }
+            }
+            distance += low.abs_diff(*c);
+            index += low * stride;
+            stride *= size;
+        }
+        index
+    }
+}
+
+/// Given the suffix sums of the weights for some dimensions, returns the sum of the weights of the contacts
+/// which position on a new dimension is between 0 and `x`, the node being at position `c` on this dimension.
+///
+/// For a set of dimensions, let `W(t)` be the sum of `w(t+|o|)` over the offsets `o` on these dimensions, where `w` gives the weight of a distance;
+/// `W(t)` is the weight of the contacts when a distance `t` has already been reached on the other dimensions.
+/// The suffix sums are the sums of the `W(t)` for `t` greater than or equal to each distance, the last one being zero.
+/// The distance already reached on the other dimensions is given by `distance`.
+fn cumulative_weight(sums: &[f64], distance: usize, c: usize, x: usize) -> f64 {
+    let sum = |s: usize| sums.get(s).copied().unwrap_or(0.);
+    if x < c {
+        sum(distance + c - x) - sum(distance + c + 1)
+    } else {
+        sum(distance) - sum(distance + c + 1) + sum(distance + 1) - sum(distance + x - c + 1)
+    }
+}
+
+/// Given the suffix sums of the weights for some dimensions (see [`cumulative_weight`]), returns the suffix sums when a new dimension is added.
+///
+/// The node is at position `c` on the new dimension, which has `size` positions.
+fn add_dimension(sums: &[f64], c: usize, size: usize) -> Vec<f64> {
+    let mut new_sums = vec![0.; sums.len()];
+    (0..sums.len() - 1).rev().for_each(|s| {
+        new_sums[s] = new_sums[s + 1] + cumulative_weight(sums, s, c, size - 1);
+    });
+    new_sums
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::{Directed, Undirected};
+    use rand::{rngs::ThreadRng, SeedableRng};
+    use rand_pcg::Pcg32;
+
+    fn params(dimensions: Vec<usize>, q: usize, r: f64) -> Vec<ParameterValue> {
+        vec![
+            ParameterValue::PositiveIntegerList(dimensions),
+            ParameterValue::PositiveInteger(q),
+            ParameterValue::PositiveFloat(r),
+        ]
+    }
+
+    #[test]
+    fn test_zero_size() {
+        assert!(
+            (KleinbergGeneratorFactory.try_with_params(params(vec![3, 0], 1, 2.))
+                as Result<BoxedGenerator<Undirected, ThreadRng>>)
+                .is_err()
+        )
+    }
+
+    #[test]
+    fn test_no_contact() {
+        let g: Graph<Directed> = KleinbergGeneratorFactory
+            .try_with_params(params(vec![3, 2], 0, 2.))
+            .unwrap()(&mut rand::thread_rng());
+        assert_eq!(6, g.n_nodes());
+        assert_eq!(14, g.n_edges());
+        assert_eq!(
+            Some(&NodeAttributeValue::FloatList(vec![1., 1.])),
+            g.node_attributes(4).get(POSITION_ATTRIBUTE)
+        );
+    }
+
+    #[test]
+    fn test_single_node() {
+        let g: Graph<Undirected> = KleinbergGeneratorFactory
+            .try_with_params(params(vec![1], 3, 2.))
+            .unwrap()(&mut rand::thread_rng());
+        assert_eq!(1, g.n_nodes());
+        assert_eq!(0, g.n_edges());
+    }
+
+    #[test]
+    fn test_contact_distribution() {
+        let dimensions = vec![4, 1, 3, 2];
+        let exponent = 1.5;
+        let model = KleinbergModel::new(dimensions.clone(), 1, exponent);
+        let n_nodes = dimensions.iter().product::<usize>();
+        let mut rng = Pcg32::seed_from_u64(0);
+        for node in [0, 5, 17] {
+            let coordinates = model.coordinates(node);
+            let expected = (0..n_nodes)
+                .map(|other| {
+                    let distance = model
+                        .coordinates(other)
+                        .iter()
+                        .zip(coordinates.iter())
+                        .map(|(a, b)| a.abs_diff(*b))
+                        .sum::<usize>();
+                    if distance == 0 {
+                        0.
+                    } else {
+                        (distance as f64).powf(-exponent)
+                    }
+                })
+                .collect::<Vec<f64>>();
+            let total = expected.iter().sum::<f64>();
+            let mut weight_sums = vec![model.distance_sums.clone()];
+            (1..dimensions.len()).rev().for_each(|k| {
+                let sums = add_dimension(&weight_sums[0], coordinates[k], dimensions[k]);
+                weight_sums.insert(0, sums);
+            });
+            let n_samples = 100_000;
+            let mut counts = vec![0; n_nodes];
+            (0..n_samples).for_each(|_| {
+                counts[model.sample_contact(&coordinates, &weight_sums, &mut rng)] += 1
+            });
+            counts.iter().zip(expected.iter()).for_each(|(c, w)| {
+                assert!((*c as f64 / n_samples as f64 - w / total).abs() < 0.01);
+            });
+        }
+    }
+
+    #[test]
+    fn test_thin_dimensions() {
+        for dimensions in [vec![1000, 1, 1], vec![200, 1, 1, 1, 1, 1], vec![1, 1, 50]] {
+            let n_nodes = dimensions.iter().product::<usize>();
+            let g: Graph<Undirected> = KleinbergGeneratorFactory
+                .try_with_params(params(dimensions, 1, 0.))
+                .unwrap()(&mut Pcg32::seed_from_u64(0));
+            assert_eq!(n_nodes, g.n_nodes());
+            assert!(g.n_edges() > n_nodes - 1);
+        }
+    }
+
+    #[test]
+    fn test_many_dimensions() {
+        let g: Graph<Directed> = KleinbergGeneratorFactory
+            .try_with_params(params(vec![4; 6], 2, 0.))
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(4096, g.n_nodes());
+        assert!(g.n_edges() > 2 * 6 * 3 * 1024);
+    }
+
+    #[test]
+    fn test_contacts() {
+        let g: Graph<Undirected> = KleinbergGeneratorFactory
+            .try_with_params(params(vec![20, 20], 2, 2.))
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(400, g.n_nodes());
+        let mut edges = g
+            .iter_edges()
+            .map(|(i, j)| (i.min(j), i.max(j)))
+            .collect::<Vec<(NodeIndexType, NodeIndexType)>>();
+        edges.sort_unstable();
+        edges.dedup();
+        assert_eq!(g.n_edges(), edges.len());
+        assert!(edges.iter().all(|(i, j)| i != j));
+        assert!(g.n_edges() > 760);
+        assert!(g.n_edges() <= 760 + 800);
+    }
+}
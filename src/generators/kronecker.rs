@@ -0,0 +1,343 @@
+use super::{BoxedGenerator, GeneratorFactory};
+use crate::{Graph, NamedParam, NodeIndexType, ParameterType, ParameterValue};
+use anyhow::{anyhow, Context, Result};
+use petgraph::EdgeType;
+use rand::{
+    distributions::{Distribution, WeightedIndex},
+    Rng,
+};
+use std::collections::HashSet;
+
+/// A factory used to build generators for [R-MAT](https://doi.org/10.1137/1.9781611972740.43) graphs, like the ones used by the Graph500 benchmark.
+///
+/// The graph has `2^scale` nodes, and `edge_factor * 2^scale` edges are sampled.
+/// Each edge is sampled by recursively choosing a quadrant of the adjacency matrix, with probabilities `a` (top left), `b` (top right), `c` (bottom left) and `d` (bottom right).
+/// Self-loops and multi-edges are discarded, so the final number of edges may be lower than the number of sampled ones.
+/// The time complexity is linear in the number of sampled edges, allowing the generation of graphs with millions of edges.
+///
+/// In directed graphs generated by this objects, edges go from the rows of the adjacency matrix to its columns.
+///
+/// Such factories can be created by passing `rmat/scale,edge_factor,a,b,c,d` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str) where
+///   - `scale` is the base-2 logarithm of the number of nodes;
+///   - `edge_factor` is the ratio of the number of sampled edges to the number of nodes;
+///   - `a`, `b`, `c` and `d` are the probabilities of the quadrants, which sum must be 1.
+///
+/// The parameters used by the Graph500 benchmark are `a=0.57`, `b=0.19`, `c=0.19` and `d=0.05`.
+#[derive(Default)]
+pub struct RMatGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for RMatGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "rmat"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator following the R-MAT model.",
+            "First parameter gives the base-2 logarithm of the number of nodes, while the second one gives the ratio of sampled edges to nodes.",
+            "The four last parameters give the probabilities of the quadrants of the adjacency matrix (top left, top right, bottom left, bottom right).",
+            "Self-loops and multi-edges are discarded.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![
+            ParameterType::PositiveInteger,
+            ParameterType::PositiveInteger,
+            ParameterType::Probability,
+            ParameterType::Probability,
+            ParameterType::Probability,
+            ParameterType::Probability,
+        ]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let context = "while building a R-MAT generator";
+        let scale = parameter_values[0].unwrap_usize();
+        let edge_factor = parameter_values[1].unwrap_usize();
+        let quadrants = parameter_values[2..]
+            .iter()
+            .map(|p| p.unwrap_f64())
+            .collect::<Vec<f64>>();
+        if (quadrants.iter().sum::<f64>() - 1.).abs() > 1e-6 {
+            return Err(anyhow!(
+                "the sum of the quadrant probabilities must be equal to 1"
+            ))
+            .context(context);
+        }
+        let sampler = KroneckerSampler::new(&quadrants, scale).context(context)?;
+        let n_edges = edge_factor
+            .checked_mul(sampler.n_nodes)
+            .ok_or_else(|| anyhow!("too many edges"))
+            .context(context)?;
+        Ok(Box::new(move |r| sampler.build_graph(n_edges, r)))
+    }
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for RMatGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+/// A factory used to build generators for [stochastic Kronecker graphs](https://arxiv.org/abs/0812.4905).
+///
+/// Given a square initiator matrix of size `s` and a number of iterations `k`, the graph has `s^k` nodes.
+/// The probability of an edge between two nodes is given by the `k`-th Kronecker power of the initiator matrix.
+/// Instead of considering all the pairs of nodes, edges are sampled by recursively choosing a cell of the initiator matrix,
+/// making the time complexity linear in the number of edges.
+///
+/// The number of sampled edges is the expected number of edges of the model, that is the sum of the initiator values raised to the power `k`.
+/// In undirected graphs, this number is halved, and the initiator matrix should be symmetric.
+/// Self-loops and multi-edges are discarded, so the final number of edges may be lower than the number of sampled ones.
+///
+/// In directed graphs generated by this objects, edges go from the rows of the adjacency matrix to its columns.
+///
+/// Such factories can be created by passing `kronecker/p11:p12:...:pss,k` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str) where
+///   - `p11:p12:...:pss` are the values of the initiator matrix, given row by row and separated by colons;
+///   - `k` is the number of Kronecker iterations.
+///
+/// The number of values of the initiator matrix must be a square, and at least one of them must be higher than zero.
+#[derive(Default)]
+pub struct StochasticKroneckerGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for StochasticKroneckerGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "kronecker"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator following the stochastic Kronecker model.",
+            "First parameter gives the initiator matrix, row by row, separated by colons (eg. 0.9:0.5:0.5:0.1).",
+            "The second one gives the number of Kronecker iterations.",
+            "Self-loops and multi-edges are discarded.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![
+            ParameterType::ProbabilityList,
+            ParameterType::PositiveInteger,
+        ]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let context = "while building a stochastic Kronecker generator";
+        let initiator = parameter_values[0].unwrap_f64_list();
+        let k = parameter_values[1].unwrap_usize();
+        let sampler = KroneckerSampler::new(initiator, k).context(context)?;
+        let mut expected_edges = initiator.iter().sum::<f64>().powi(k as i32);
+        if !Ty::is_directed() {
+            expected_edges /= 2.;
+        }
+        let n_edges = expected_edges.round() as usize;
+        Ok(Box::new(move |r| sampler.build_graph(n_edges, r)))
+    }
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for StochasticKroneckerGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+/// An object used to sample edges by recursively choosing cells in an initiator matrix.
+struct KroneckerSampler {
+    size: usize,
+    k: usize,
+    n_nodes: usize,
+    cells: WeightedIndex<f64>,
+}
+
+impl KroneckerSampler {
+    fn new(initiator: &[f64], k: usize) -> Result<Self> {
+        let size = (initiator.len() as f64).sqrt().round() as usize;
+        if size * size != initiator.len() {
+            return Err(anyhow!(
+                "the number of values of the initiator matrix must be a square"
+            ));
+        }
+        let n_nodes = u32::try_from(k)
+            .ok()
+            .and_then(|k| size.checked_pow(k))
+            .ok_or_else(|| anyhow!("too many nodes"))?;
+        let cells = WeightedIndex::new(initiator)
+            .map_err(|_| anyhow!("the initiator matrix must contain a value higher than 0"))?;
+        Ok(Self {
+            size,
+            k,
+            n_nodes,
+            cells,
+        })
+    }
+
+    fn sample_edge<R>(&self, r: &mut R) -> (NodeIndexType, NodeIndexType)
+    where
+        R: Rng,
+    {
+        (0..self.k).fold((0, 0), |(i, j), _| {
+            let cell = self.cells.sample(r);
+            (
+                i * self.size + cell / self.size,
+                j * self.size + cell % self.size,
+            )
+        })
+    }
+
+    fn build_graph<Ty, R>(&self, n_edges: usize, r: &mut R) -> Graph<Ty>
+    where
+        R: Rng,
+        Ty: EdgeType,
+    {
+        let mut g = Graph::with_capacity(self.n_nodes, n_edges);
+        (0..self.n_nodes).for_each(|_| g.new_node());
+        let mut edges = HashSet::with_capacity(n_edges);
+        (0..n_edges).for_each(|_| {
+            let (i, j) = self.sample_edge(r);
+            if i == j {
+                return;
+            }
+            let key = if Ty::is_directed() {
+                (i, j)
+            } else {
+                (i.min(j), i.max(j))
+            };
+            if edges.insert(key) {
+                g.new_edge(i, j);
+            }
+        });
+        g
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::{Directed, Undirected};
+    use rand::{rngs::ThreadRng, SeedableRng};
+    use rand_pcg::Pcg32;
+
+    fn rmat_params(scale: usize, edge_factor: usize, quadrants: [f64; 4]) -> Vec<ParameterValue> {
+        let mut params = vec![
+            ParameterValue::PositiveInteger(scale),
+            ParameterValue::PositiveInteger(edge_factor),
+        ];
+        params.append(
+            &mut quadrants
+                .iter()
+                .map(|p| ParameterValue::Probability(*p))
+                .collect(),
+        );
+        params
+    }
+
+    #[test]
+    fn test_rmat_wrong_sum() {
+        assert!(
+            (RMatGeneratorFactory.try_with_params(rmat_params(4, 4, [0.5, 0.2, 0.2, 0.2]))
+                as Result<BoxedGenerator<Directed, ThreadRng>>)
+                .is_err()
+        )
+    }
+
+    #[test]
+    fn test_rmat_only_self_loops() {
+        let g: Graph<Directed> = RMatGeneratorFactory
+            .try_with_params(rmat_params(4, 4, [1., 0., 0., 0.]))
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(16, g.n_nodes());
+        assert_eq!(0, g.n_edges());
+    }
+
+    #[test]
+    fn test_rmat_simple_graph() {
+        let g: Graph<Undirected> = RMatGeneratorFactory
+            .try_with_params(rmat_params(10, 16, [0.57, 0.19, 0.19, 0.05]))
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(1024, g.n_nodes());
+        assert!(g.n_edges() > 0 && g.n_edges() <= 16 * 1024);
+        let mut edges = HashSet::new();
+        g.iter_edges().for_each(|(i, j)| {
+            assert_ne!(i, j);
+            assert!(edges.insert((i.min(j), i.max(j))));
+        });
+    }
+
+    #[test]
+    fn test_rmat_reproducible() {
+        let generator = RMatGeneratorFactory
+            .try_with_params(rmat_params(8, 8, [0.57, 0.19, 0.19, 0.05]))
+            .unwrap();
+        let g1: Graph<Directed> = generator(&mut Pcg32::seed_from_u64(0));
+        let g2: Graph<Directed> = generator(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(
+            g1.iter_edges()
+                .collect::<Vec<(NodeIndexType, NodeIndexType)>>(),
+            g2.iter_edges()
+                .collect::<Vec<(NodeIndexType, NodeIndexType)>>()
+        );
+    }
+
+    #[test]
+    fn test_kronecker_not_square() {
+        assert!((StochasticKroneckerGeneratorFactory.try_with_params(vec![
+            ParameterValue::ProbabilityList(vec![0.5, 0.5, 0.5]),
+            ParameterValue::PositiveInteger(2),
+        ]) as Result<BoxedGenerator<Directed, ThreadRng>>)
+            .is_err())
+    }
+
+    #[test]
+    fn test_kronecker_null_initiator() {
+        assert!((StochasticKroneckerGeneratorFactory.try_with_params(vec![
+            ParameterValue::ProbabilityList(vec![0.; 4]),
+            ParameterValue::PositiveInteger(2),
+        ]) as Result<BoxedGenerator<Directed, ThreadRng>>)
+            .is_err())
+    }
+
+    #[test]
+    fn test_kronecker_single_cell() {
+        let g: Graph<Directed> = StochasticKroneckerGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::ProbabilityList(vec![0., 1., 0., 0.]),
+                ParameterValue::PositiveInteger(2),
+            ])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(4, g.n_nodes());
+        assert_eq!(
+            vec![(0, 3)],
+            g.iter_edges()
+                .collect::<Vec<(NodeIndexType, NodeIndexType)>>()
+        );
+    }
+
+    #[test]
+    fn test_kronecker_size() {
+        let g: Graph<Directed> = StochasticKroneckerGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::ProbabilityList(vec![0.9, 0.5, 0.5, 0.1, 0.3, 0.2, 0.4, 0.6, 0.8]),
+                ParameterValue::PositiveInteger(4),
+            ])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(81, g.n_nodes());
+        assert!(g.n_edges() <= 4.3_f64.powi(4).round() as usize);
+    }
+}
@@ -0,0 +1,398 @@
+use super::{power_law::PowerLaw, BoxedGenerator, GeneratorFactory, COMMUNITY_ATTRIBUTE};
+use crate::{Graph, NamedParam, NodeAttributeValue, NodeIndexType, ParameterType, ParameterValue};
+use anyhow::{anyhow, Context, Result};
+use petgraph::EdgeType;
+use rand::{prelude::Distribution, seq::SliceRandom, Rng};
+use std::collections::HashSet;
+
+const MAX_WIRING_ROUNDS: usize = 10;
+
+/// A factory used to build generators for [Lancichinetti-Fortunato-Radicchi](https://en.wikipedia.org/wiki/Lancichinetti%E2%80%93Fortunato%E2%80%93Radicchi_benchmark) benchmark graphs.
+///
+/// Node degrees follow a power law which lower bound is chosen to match the requested average degree.
+/// Community sizes follow another power law, with values between the lowest and the highest degrees.
+/// Each node shares a fraction `1-mu` of its edges with the nodes of its community, and a fraction `mu` with other nodes.
+/// The edges are then built using a configuration model; self-loops, multi-edges and external edges that fall inside a community are discarded,
+/// as are the internal edges of nodes that do not fit in any community, so the final degrees may be slightly lower than the sampled ones.
+///
+/// The community of each node is stored as its `community` attribute, allowing display engines such as GraphML to export the ground truth.
+///
+/// In directed graphs generated with this object, the direction of each edge is chosen uniformly at random.
+///
+/// Such factories can be created by passing `lfr/n,k,maxk,tau1,tau2,mu` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str) where
+///   - `n` is the size of graph to produce;
+///   - `k` is the average degree;
+///   - `maxk` is the maximal degree;
+///   - `tau1` is the exponent of the degree distribution;
+///   - `tau2` is the exponent of the community size distribution;
+///   - `mu` is the mixing parameter.
+///
+/// Parameter `k` must be higher than zero and not higher than `maxk`, which must itself be lower than `n`.
+/// Moreover, `k` must be reachable by a power law with exponent `tau1` which values are between 1 and `maxk`.
+#[derive(Default)]
+pub struct LancichinettiFortunatoRadicchiGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for LancichinettiFortunatoRadicchiGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "lfr"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator following the Lancichinetti-Fortunato-Radicchi benchmark.",
+            "First parameter gives the number of nodes, the second one the average degree and the third one the maximal degree.",
+            "The fourth and fifth parameters give the exponents of the degree and community size distributions.",
+            "The last parameter is the mixing parameter, that is the fraction of edges of each node that leave its community.",
+            "The community of each node is exported as a node attribute.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![
+            ParameterType::PositiveInteger,
+            ParameterType::PositiveFloat,
+            ParameterType::PositiveInteger,
+            ParameterType::PositiveFloat,
+            ParameterType::PositiveFloat,
+            ParameterType::Probability,
+        ]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let context = "while building a Lancichinetti-Fortunato-Radicchi generator";
+        let n = parameter_values[0].unwrap_usize();
+        let k = parameter_values[1].unwrap_f64();
+        let max_k = parameter_values[2].unwrap_usize();
+        let tau1 = parameter_values[3].unwrap_f64();
+        let tau2 = parameter_values[4].unwrap_f64();
+        let mu = parameter_values[5].unwrap_f64();
+        if max_k >= n {
+            return Err(anyhow!(
+                r#"third parameter ("maxk") must be lower than the first one ("n")"#
+            ))
+            .context(context);
+        }
+        if k <= 0. || k > max_k as f64 {
+            return Err(anyhow!(
+                r#"second parameter ("k") must be higher than 0 and not higher than the third one ("maxk")"#
+            ))
+            .context(context);
+        }
+        let degree_distribution = PowerLaw::with_mean(max_k as f64, tau1, k)
+            .ok_or_else(|| {
+                anyhow!(
+                    r#"no power law with exponent {} and values between 1 and {} has an average of {}"#,
+                    tau1,
+                    max_k,
+                    k
+                )
+            })
+            .context(context)?;
+        Ok(Box::new(move |r| {
+            build_graph(n, max_k, degree_distribution, tau2, mu, r)
+        }))
+    }
+}
+
+fn build_graph<Ty, R>(
+    n: usize,
+    max_k: usize,
+    degree_distribution: PowerLaw,
+    tau2: f64,
+    mu: f64,
+    r: &mut R,
+) -> Graph<Ty>
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    let degrees = sample_degrees(n, max_k, degree_distribution, r);
+    let mut internal_degrees = degrees
+        .iter()
+        .map(|d| ((1. - mu) * *d as f64).round() as usize)
+        .collect::<Vec<usize>>();
+    let external_degrees = (0..n)
+        .map(|i| degrees[i] - internal_degrees[i])
+        .collect::<Vec<usize>>();
+    let community_sizes = sample_community_sizes(&degrees, &internal_degrees, tau2, r);
+    let communities = assign_communities(&community_sizes, &mut internal_degrees, r);
+    let mut edges = HashSet::new();
+    let mut community_members = vec![vec![]; community_sizes.len()];
+    (0..n).for_each(|i| community_members[communities[i]].push(i));
+    for members in community_members.iter() {
+        let mut stubs = members
+            .iter()
+            .flat_map(|i| std::iter::repeat_n(*i, internal_degrees[*i]))
+            .collect::<Vec<NodeIndexType>>();
+        if stubs.len() & 1 == 1 {
+            stubs.pop();
+        }
+        wire(stubs, |_, _| true, &mut edges, r);
+    }
+    let mut external_stubs = (0..n)
+        .flat_map(|i| std::iter::repeat_n(i, external_degrees[i]))
+        .collect::<Vec<NodeIndexType>>();
+    if external_stubs.len() & 1 == 1 {
+        external_stubs.pop();
+    }
+    wire(
+        external_stubs,
+        |i, j| communities[i] != communities[j],
+        &mut edges,
+        r,
+    );
+    let mut g = Graph::with_capacity(n, edges.len());
+    (0..n).for_each(|i| {
+        g.new_node();
+        g.set_node_attribute(
+            i,
+            COMMUNITY_ATTRIBUTE,
+            NodeAttributeValue::PositiveInteger(communities[i]),
+        );
+    });
+    let mut edges = edges
+        .into_iter()
+        .collect::<Vec<(NodeIndexType, NodeIndexType)>>();
+    edges.sort_unstable();
+    edges.into_iter().for_each(|(i, j)| {
+        if Ty::is_directed() && r.gen_bool(0.5) {
+            g.new_edge(j, i);
+        } else {
+            g.new_edge(i, j);
+        }
+    });
+    g
+}
+
+fn sample_degrees<R>(n: usize, max_k: usize, degree_distribution: PowerLaw, r: &mut R) -> Vec<usize>
+where
+    R: Rng,
+{
+    let mut degrees = (0..n)
+        .map(|_| (degree_distribution.sample(r).round() as usize).clamp(1, max_k))
+        .collect::<Vec<usize>>();
+    if degrees.iter().sum::<usize>() & 1 == 1 {
+        match degrees.iter().position(|d| *d < max_k) {
+            Some(i) => degrees[i] += 1,
+            None => degrees[0] -= 1,
+        }
+    }
+    degrees
+}
+
+fn sample_community_sizes<R>(
+    degrees: &[usize],
+    internal_degrees: &[usize],
+    tau2: f64,
+    r: &mut R,
+) -> Vec<usize>
+where
+    R: Rng,
+{
+    let n = degrees.len();
+    let min_size = degrees.iter().min().copied().unwrap_or(1).clamp(1, n);
+    let max_size = (1 + internal_degrees.iter().max().copied().unwrap_or_default())
+        .max(degrees.iter().max().copied().unwrap_or(1))
+        .min(n);
+    let size_distribution = PowerLaw::new(min_size as f64, max_size as f64, tau2);
+    let mut sizes = vec![];
+    let mut total = 0;
+    while total < n {
+        let s = (size_distribution.sample(r).round() as usize).clamp(min_size, max_size);
+        sizes.push(s);
+        total += s;
+    }
+    let last = sizes.pop().unwrap() - (total - n);
+    if last >= min_size || sizes.is_empty() {
+        sizes.push(last);
+        return sizes;
+    }
+    let mut candidates = (0..sizes.len())
+        .filter(|i| sizes[*i] < max_size)
+        .collect::<Vec<usize>>();
+    candidates.shuffle(r);
+    let mut remaining = last;
+    for i in candidates.into_iter().cycle() {
+        if remaining == 0 || sizes.iter().all(|s| *s >= max_size) {
+            break;
+        }
+        if sizes[i] < max_size {
+            sizes[i] += 1;
+            remaining -= 1;
+        }
+    }
+    if remaining > 0 {
+        sizes.push(remaining);
+    }
+    sizes
+}
+
+fn assign_communities<R>(
+    community_sizes: &[usize],
+    internal_degrees: &mut [usize],
+    r: &mut R,
+) -> Vec<usize>
+where
+    R: Rng,
+{
+    let mut sorted_communities = (0..community_sizes.len()).collect::<Vec<usize>>();
+    sorted_communities.sort_unstable_by_key(|c| std::cmp::Reverse(community_sizes[*c]));
+    let mut sorted_nodes = (0..internal_degrees.len()).collect::<Vec<usize>>();
+    sorted_nodes.sort_unstable_by_key(|i| std::cmp::Reverse(internal_degrees[*i]));
+    let mut free_slots = community_sizes.to_vec();
+    let mut eligible_slots = vec![];
+    let mut next_community = 0;
+    let mut communities = vec![0; internal_degrees.len()];
+    for i in sorted_nodes {
+        while next_community < sorted_communities.len()
+            && community_sizes[sorted_communities[next_community]] > internal_degrees[i]
+        {
+            let c = sorted_communities[next_community];
+            eligible_slots.extend(std::iter::repeat_n(c, free_slots[c]));
+            next_community += 1;
+        }
+        let c = if eligible_slots.is_empty() {
+            let c = (0..community_sizes.len())
+                .find(|c| free_slots[*c] > 0)
+                .unwrap();
+            internal_degrees[i] = community_sizes[c] - 1;
+            c
+        } else {
+            eligible_slots.swap_remove(r.gen_range(0..eligible_slots.len()))
+        };
+        free_slots[c] -= 1;
+        communities[i] = c;
+    }
+    communities
+}
+
+fn wire<F, R>(
+    mut stubs: Vec<NodeIndexType>,
+    accept: F,
+    edges: &mut HashSet<(NodeIndexType, NodeIndexType)>,
+    r: &mut R,
+) where
+    F: Fn(NodeIndexType, NodeIndexType) -> bool,
+    R: Rng,
+{
+    for _ in 0..MAX_WIRING_ROUNDS {
+        if stubs.len() < 2 {
+            return;
+        }
+        stubs.shuffle(r);
+        let mut rejected = vec![];
+        stubs.chunks_exact(2).for_each(|pair| {
+            let edge = (pair[0].min(pair[1]), pair[0].max(pair[1]));
+            if edge.0 == edge.1 || !accept(edge.0, edge.1) || !edges.insert(edge) {
+                rejected.extend_from_slice(pair);
+            }
+        });
+        if rejected.len() == stubs.len() {
+            return;
+        }
+        stubs = rejected;
+    }
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for LancichinettiFortunatoRadicchiGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::{Directed, Undirected};
+    use rand::{rngs::ThreadRng, SeedableRng};
+    use rand_pcg::Pcg32;
+
+    fn lfr_params(n: usize, k: f64, max_k: usize, mu: f64) -> Vec<ParameterValue> {
+        vec![
+            ParameterValue::PositiveInteger(n),
+            ParameterValue::PositiveFloat(k),
+            ParameterValue::PositiveInteger(max_k),
+            ParameterValue::PositiveFloat(2.5),
+            ParameterValue::PositiveFloat(1.5),
+            ParameterValue::Probability(mu),
+        ]
+    }
+
+    fn community(g: &Graph<Undirected>, i: NodeIndexType) -> usize {
+        match g.node_attributes(i).get(COMMUNITY_ATTRIBUTE) {
+            Some(NodeAttributeValue::PositiveInteger(c)) => *c,
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_max_k_not_lower_than_n() {
+        assert!((LancichinettiFortunatoRadicchiGeneratorFactory
+            .try_with_params(lfr_params(10, 5., 10, 0.1))
+            as Result<BoxedGenerator<Directed, ThreadRng>>)
+            .is_err())
+    }
+
+    #[test]
+    fn test_k_higher_than_max_k() {
+        assert!((LancichinettiFortunatoRadicchiGeneratorFactory
+            .try_with_params(lfr_params(100, 20., 10, 0.1))
+            as Result<BoxedGenerator<Directed, ThreadRng>>)
+            .is_err())
+    }
+
+    #[test]
+    fn test_k_unreachable() {
+        assert!((LancichinettiFortunatoRadicchiGeneratorFactory
+            .try_with_params(lfr_params(100, 1., 10, 0.1))
+            as Result<BoxedGenerator<Directed, ThreadRng>>)
+            .is_err())
+    }
+
+    #[test]
+    fn test_simple_graph_with_communities() {
+        let mut rng = Pcg32::seed_from_u64(0);
+        let g: Graph<Undirected> = LancichinettiFortunatoRadicchiGeneratorFactory
+            .try_with_params(lfr_params(500, 10., 40, 0.2))
+            .unwrap()(&mut rng);
+        assert_eq!(500, g.n_nodes());
+        let edges = g
+            .iter_edges()
+            .collect::<Vec<(NodeIndexType, NodeIndexType)>>();
+        assert!(edges.iter().all(|(i, j)| i != j));
+        assert_eq!(
+            edges.len(),
+            edges
+                .iter()
+                .map(|(i, j)| (*i.min(j), *i.max(j)))
+                .collect::<HashSet<_>>()
+                .len()
+        );
+        let n_external = edges
+            .iter()
+            .filter(|(i, j)| community(&g, *i) != community(&g, *j))
+            .count();
+        let mixing = n_external as f64 / edges.len() as f64;
+        assert!((0.1..0.3).contains(&mixing));
+    }
+
+    #[test]
+    fn test_no_mixing() {
+        let mut rng = Pcg32::seed_from_u64(0);
+        let g: Graph<Undirected> = LancichinettiFortunatoRadicchiGeneratorFactory
+            .try_with_params(lfr_params(200, 5., 20, 0.))
+            .unwrap()(&mut rng);
+        assert!(g.n_edges() > 0);
+        assert!(g
+            .iter_edges()
+            .all(|(i, j)| community(&g, i) == community(&g, j)));
+    }
+}
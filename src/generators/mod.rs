@@ -227,18 +227,104 @@
 mod barabasi_albert_generator;
 pub use barabasi_albert_generator::BarabasiAlbertGeneratorFactory;
 
+mod kronecker;
+pub use kronecker::{RMatGeneratorFactory, StochasticKroneckerGeneratorFactory};
+
+mod lancichinetti_fortunato_radicchi;
+pub use lancichinetti_fortunato_radicchi::LancichinettiFortunatoRadicchiGeneratorFactory;
+
 mod path_generator;
 pub use path_generator::PathGeneratorFactory;
 
+mod bollobas_borgs_chayes_riordan;
+pub use bollobas_borgs_chayes_riordan::BollobasBorgsChayesRiordanGeneratorFactory;
+
+mod chung_lu;
+pub use chung_lu::{ChungLuFileGeneratorFactory, ChungLuGeneratorFactory};
+
+mod complete_bipartite_generator;
+pub use complete_bipartite_generator::CompleteBipartiteGeneratorFactory;
+
+mod complete_generator;
+pub use complete_generator::CompleteGeneratorFactory;
+
+mod configuration_model;
+pub use configuration_model::{
+    ConfigurationModelFileGeneratorFactory, ConfigurationModelGeneratorFactory,
+};
+
+mod copying_model;
+pub use copying_model::CopyingModelGeneratorFactory;
+
+mod cycle_generator;
+pub use cycle_generator::CycleGeneratorFactory;
+
+mod degree_sequence;
+
+mod duplication_divergence;
+pub use duplication_divergence::DuplicationDivergenceGeneratorFactory;
+
 mod erdos_renyi;
-pub use erdos_renyi::ErdosRenyiGeneratorFactory;
+pub use erdos_renyi::{ErdosRenyiGeneratorFactory, ErdosRenyiGnmGeneratorFactory};
+
+mod file_generator;
+pub use file_generator::{FileDirectoryGeneratorFactory, FileGeneratorFactory};
+
+mod forest_fire;
+pub use forest_fire::ForestFireGeneratorFactory;
+
+mod grid_generator;
+pub use grid_generator::{GridGeneratorFactory, TorusGeneratorFactory};
+
+mod holme_kim;
+pub use holme_kim::HolmeKimGeneratorFactory;
+
+mod hyperbolic;
+pub use hyperbolic::HyperbolicGeneratorFactory;
+
+mod hypercube_generator;
+pub use hypercube_generator::HypercubeGeneratorFactory;
+
+mod kleinberg;
+pub use kleinberg::KleinbergGeneratorFactory;
+
+mod power_law;
+
+mod random_dag;
+pub use random_dag::{RandomDagGeneratorFactory, RandomDagOutDegreeGeneratorFactory};
+
+mod random_bipartite;
+pub use random_bipartite::{
+    BipartiteConfigurationModelGeneratorFactory, RandomBipartiteGeneratorFactory,
+};
+
+mod random_geometric;
+pub use random_geometric::RandomGeometricGeneratorFactory;
+
+mod random_regular;
+pub use random_regular::RandomRegularGeneratorFactory;
+
+mod random_tournament;
+pub use random_tournament::RandomTournamentGeneratorFactory;
+
+mod random_tree;
+pub use random_tree::{RecursiveTreeGeneratorFactory, UniformTreeGeneratorFactory};
+
+mod star_generator;
+pub use star_generator::StarGeneratorFactory;
+
+mod stochastic_block_model;
+pub use stochastic_block_model::StochasticBlockModelGeneratorFactory;
 
 mod tree_generator;
-pub use tree_generator::TreeGeneratorFactory;
+pub use tree_generator::{KaryTreeGeneratorFactory, TreeGeneratorFactory};
 
 mod watts_strogatz;
 pub use watts_strogatz::WattsStrogatzGeneratorFactory;
 
+mod wheel_generator;
+pub use wheel_generator::WheelGeneratorFactory;
+
 use crate::{core::named_param, Graph, NamedParam};
 use anyhow::{Context, Result};
 use lazy_static::lazy_static;
@@ -261,6 +347,15 @@ use rand_pcg::Pcg32;
 /// ```
 pub type BoxedGenerator<Ty, R> = Box<dyn Fn(&mut R) -> Graph<Ty> + Sync + Send>;
 
+/// The name of the node attribute used by generators to store the community of the nodes.
+pub(crate) const COMMUNITY_ATTRIBUTE: &str = "community";
+
+/// The name of the node attribute used by generators to store the coordinates of the nodes in a space.
+pub(crate) const POSITION_ATTRIBUTE: &str = "pos";
+
+/// The name of the node attribute used by generators to store the side of the nodes in bipartite graphs.
+pub(crate) const SIDE_ATTRIBUTE: &str = "side";
+
 /// A trait for objects that produce graph generators.
 pub trait GeneratorFactory<Ty, R>: NamedParam<BoxedGenerator<Ty, R>>
 where
@@ -270,22 +365,88 @@ where
 }
 
 lazy_static! {
-    pub(crate) static ref GENERATOR_FACTORIES_DIRECTED_PCG32: [Box<dyn GeneratorFactory<Directed, Pcg32> + Sync>; 5] = [
-        Box::new(BarabasiAlbertGeneratorFactory::default()),
-        Box::new(PathGeneratorFactory::default()),
-        Box::new(ErdosRenyiGeneratorFactory::default()),
-        Box::new(TreeGeneratorFactory::default()),
-        Box::new(WattsStrogatzGeneratorFactory::default()),
+    pub(crate) static ref GENERATOR_FACTORIES_DIRECTED_PCG32: [Box<dyn GeneratorFactory<Directed, Pcg32> + Sync>; 41] = [
+        Box::new(BarabasiAlbertGeneratorFactory),
+        Box::new(HolmeKimGeneratorFactory),
+        Box::new(DuplicationDivergenceGeneratorFactory),
+        Box::new(PathGeneratorFactory),
+        Box::new(ErdosRenyiGeneratorFactory),
+        Box::new(ErdosRenyiGnmGeneratorFactory),
+        Box::new(TreeGeneratorFactory),
+        Box::new(KaryTreeGeneratorFactory),
+        Box::new(UniformTreeGeneratorFactory),
+        Box::new(RecursiveTreeGeneratorFactory),
+        Box::new(WattsStrogatzGeneratorFactory),
+        Box::new(KleinbergGeneratorFactory),
+        Box::new(StochasticBlockModelGeneratorFactory),
+        Box::new(LancichinettiFortunatoRadicchiGeneratorFactory),
+        Box::new(ConfigurationModelGeneratorFactory),
+        Box::new(ConfigurationModelFileGeneratorFactory),
+        Box::new(ChungLuGeneratorFactory),
+        Box::new(ChungLuFileGeneratorFactory),
+        Box::new(FileGeneratorFactory),
+        Box::new(FileDirectoryGeneratorFactory),
+        Box::new(RandomRegularGeneratorFactory),
+        Box::new(GridGeneratorFactory),
+        Box::new(TorusGeneratorFactory),
+        Box::new(HypercubeGeneratorFactory),
+        Box::new(CompleteGeneratorFactory),
+        Box::new(StarGeneratorFactory),
+        Box::new(CycleGeneratorFactory),
+        Box::new(WheelGeneratorFactory),
+        Box::new(CompleteBipartiteGeneratorFactory),
+        Box::new(RandomBipartiteGeneratorFactory),
+        Box::new(BipartiteConfigurationModelGeneratorFactory),
+        Box::new(RMatGeneratorFactory),
+        Box::new(StochasticKroneckerGeneratorFactory),
+        Box::new(RandomGeometricGeneratorFactory),
+        Box::new(HyperbolicGeneratorFactory),
+        Box::new(BollobasBorgsChayesRiordanGeneratorFactory),
+        Box::new(RandomDagGeneratorFactory),
+        Box::new(RandomDagOutDegreeGeneratorFactory),
+        Box::new(RandomTournamentGeneratorFactory),
+        Box::new(ForestFireGeneratorFactory),
+        Box::new(CopyingModelGeneratorFactory),
     ];
 }
 
 lazy_static! {
-    pub(crate) static ref GENERATOR_FACTORIES_UNDIRECTED_PCG32: [Box<dyn GeneratorFactory<Undirected, Pcg32> + Sync>; 5] = [
-        Box::new(BarabasiAlbertGeneratorFactory::default()),
-        Box::new(PathGeneratorFactory::default()),
-        Box::new(ErdosRenyiGeneratorFactory::default()),
-        Box::new(TreeGeneratorFactory::default()),
-        Box::new(WattsStrogatzGeneratorFactory::default()),
+    pub(crate) static ref GENERATOR_FACTORIES_UNDIRECTED_PCG32: [Box<dyn GeneratorFactory<Undirected, Pcg32> + Sync>; 35] = [
+        Box::new(BarabasiAlbertGeneratorFactory),
+        Box::new(HolmeKimGeneratorFactory),
+        Box::new(DuplicationDivergenceGeneratorFactory),
+        Box::new(PathGeneratorFactory),
+        Box::new(ErdosRenyiGeneratorFactory),
+        Box::new(ErdosRenyiGnmGeneratorFactory),
+        Box::new(TreeGeneratorFactory),
+        Box::new(KaryTreeGeneratorFactory),
+        Box::new(UniformTreeGeneratorFactory),
+        Box::new(RecursiveTreeGeneratorFactory),
+        Box::new(WattsStrogatzGeneratorFactory),
+        Box::new(KleinbergGeneratorFactory),
+        Box::new(StochasticBlockModelGeneratorFactory),
+        Box::new(LancichinettiFortunatoRadicchiGeneratorFactory),
+        Box::new(ConfigurationModelGeneratorFactory),
+        Box::new(ConfigurationModelFileGeneratorFactory),
+        Box::new(ChungLuGeneratorFactory),
+        Box::new(ChungLuFileGeneratorFactory),
+        Box::new(FileGeneratorFactory),
+        Box::new(FileDirectoryGeneratorFactory),
+        Box::new(RandomRegularGeneratorFactory),
+        Box::new(GridGeneratorFactory),
+        Box::new(TorusGeneratorFactory),
+        Box::new(HypercubeGeneratorFactory),
+        Box::new(CompleteGeneratorFactory),
+        Box::new(StarGeneratorFactory),
+        Box::new(CycleGeneratorFactory),
+        Box::new(WheelGeneratorFactory),
+        Box::new(CompleteBipartiteGeneratorFactory),
+        Box::new(RandomBipartiteGeneratorFactory),
+        Box::new(BipartiteConfigurationModelGeneratorFactory),
+        Box::new(RMatGeneratorFactory),
+        Box::new(StochasticKroneckerGeneratorFactory),
+        Box::new(RandomGeometricGeneratorFactory),
+        Box::new(HyperbolicGeneratorFactory),
     ];
 }
 
@@ -347,6 +508,20 @@ pub fn undirected_generator_factory_from_str(s: &str) -> Result<BoxedGenerator<U
         .context("while building a generator from a string")
 }
 
+/// Returns a path in the temporary directory, made unique to the running test process.
+#[cfg(test)]
+fn tmp_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("crusti_g2io_test_{}_{}", std::process::id(), name))
+}
+
+/// Writes a file in the temporary directory and returns its path.
+#[cfg(test)]
+fn write_tmp_file(name: &str, content: &str) -> String {
+    let path = tmp_path(name);
+    std::fs::write(&path, content).unwrap();
+    path.to_str().unwrap().to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
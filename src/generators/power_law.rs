@@ -0,0 +1,110 @@
+use rand::{distributions::Distribution, Rng};
+
+/// A continuous power law distribution, truncated to an interval `[min, max]`.
+///
+/// The density of a value `x` is proportional to `x^(-exponent)`.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct PowerLaw {
+    min: f64,
+    max: f64,
+    exponent: f64,
+}
+
+const EPSILON: f64 = 1e-9;
+
+impl PowerLaw {
+    /// Builds a new power law distribution.
+    ///
+    /// Bounds must be strictly positive, and `min` must not be higher than `max`.
+    pub(crate) fn new(min: f64, max: f64, exponent: f64) -> Self {
+        debug_assert!(min > 0. && min <= max);
+        Self { min, max, exponent }
+    }
+
+    /// Builds a power law distribution with the given upper bound and exponent which mean is the one provided.
+    ///
+    /// The lower bound is searched in `[1, max]`.
+    /// In case no such lower bound gives the expected mean, `None` is returned.
+    pub(crate) fn with_mean(max: f64, exponent: f64, mean: f64) -> Option<Self> {
+        if mean < PowerLaw::new(1., max, exponent).mean() - EPSILON || mean > max + EPSILON {
+            return None;
+        }
+        let (mut low, mut high) = (1., max);
+        for _ in 0..100 {
+            let mid = (low + high) / 2.;
+            if PowerLaw::new(mid, max, exponent).mean() < mean {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        Some(PowerLaw::new((low + high) / 2., max, exponent))
+    }
+
+    /// Returns the mean of the distribution.
+    pub(crate) fn mean(&self) -> f64 {
+        let (a, b, t) = (self.min, self.max, self.exponent);
+        if b - a < EPSILON {
+            a
+        } else if (t - 1.).abs() < EPSILON {
+            (b - a) / (b / a).ln()
+        } else if (t - 2.).abs() < EPSILON {
+            (b / a).ln() / (1. / a - 1. / b)
+        } else {
+            (1. - t) / (2. - t) * (b.powf(2. - t) - a.powf(2. - t))
+                / (b.powf(1. - t) - a.powf(1. - t))
+        }
+    }
+}
+
+impl Distribution<f64> for PowerLaw {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let (a, b, t) = (self.min, self.max, self.exponent);
+        let u: f64 = rng.gen();
+        let x = if (t - 1.).abs() < EPSILON {
+            a * (b / a).powf(u)
+        } else {
+            let g = 1. - t;
+            (a.powf(g) + u * (b.powf(g) - a.powf(g))).powf(1. / g)
+        };
+        x.clamp(a, b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_pcg::Pcg32;
+
+    #[test]
+    fn test_mean_uniform() {
+        assert!((PowerLaw::new(1., 3., 0.).mean() - 2.).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_mean_special_exponents() {
+        let mean_1 = PowerLaw::new(1., 10., 1.).mean();
+        assert!((PowerLaw::new(1., 10., 1. + 1e-6).mean() - mean_1).abs() < 1e-4);
+        let mean_2 = PowerLaw::new(1., 10., 2.).mean();
+        assert!((PowerLaw::new(1., 10., 2. + 1e-6).mean() - mean_2).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_with_mean() {
+        let distribution = PowerLaw::with_mean(50., 2.5, 10.).unwrap();
+        assert!((distribution.mean() - 10.).abs() < 1e-6);
+        assert!(PowerLaw::with_mean(50., 2.5, 60.).is_none());
+        assert!(PowerLaw::with_mean(50., 2.5, 1.).is_none());
+    }
+
+    #[test]
+    fn test_samples_in_bounds() {
+        let distribution = PowerLaw::new(2., 5., 2.5);
+        let mut rng = Pcg32::seed_from_u64(0);
+        (0..1000).for_each(|_| {
+            let x = distribution.sample(&mut rng);
+            assert!((2. ..=5.).contains(&x));
+        });
+    }
+}
@@ -0,0 +1,391 @@
+use super::{
+    configuration_model::{balance_sequences, stubs},
+    degree_sequence::DegreeDistribution,
+    erdos_renyi, BoxedGenerator, GeneratorFactory, SIDE_ATTRIBUTE,
+};
+use crate::{Graph, NamedParam, NodeAttributeValue, NodeIndexType, ParameterType, ParameterValue};
+use anyhow::{anyhow, Context, Result};
+use petgraph::EdgeType;
+use rand::{seq::SliceRandom, Rng};
+use std::{collections::HashSet, str::FromStr};
+
+/// The side a random bipartite graph is projected onto, if any.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Projection {
+    /// The bipartite graph is returned as is
+    None,
+    /// The graph is projected onto the nodes of the first side
+    First,
+    /// The graph is projected onto the nodes of the second side
+    Second,
+}
+
+impl FromStr for Projection {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "none" => Ok(Projection::None),
+            "first" => Ok(Projection::First),
+            "second" => Ok(Projection::Second),
+            _ => Err(anyhow!(
+                r#"unknown projection "{}" (expected "none", "first" or "second")"#,
+                s
+            )),
+        }
+    }
+}
+
+/// A factory used to build generators for random bipartite graphs, in which each edge between the two sides appears with a given probability.
+///
+/// The first `n1` nodes form the first side of the graph, while the `n2` following ones form the second side.
+/// The side of each node (0 or 1) is stored as its `side` attribute.
+///
+/// The graph can be projected onto one of its sides, as for [affiliation networks](https://en.wikipedia.org/wiki/Bipartite_network_projection):
+/// the projected graph is made of the nodes of this side, two of them being linked if they share a neighbor in the other side.
+///
+/// In directed graphs generated by this objects, edges go from the first side to the second one,
+/// while the linked nodes of projected graphs are connected by two edges, one in each direction.
+///
+/// Such factories can be created by passing `bipartite/n1,n2,p,projection` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str) where
+///   - `n1` and `n2` are the sizes of the sides;
+///   - `p` is the probability each edge appears in the graph;
+///   - `projection` is one of `none`, `first` and `second`.
+#[derive(Default)]
+pub struct RandomBipartiteGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for RandomBipartiteGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "bipartite"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator producing random bipartite graphs.",
+            "First two parameters give the sizes of the sides, while the third one gives the probability each edge appears in the graph.",
+            r#"The last parameter is "none" to get the bipartite graph, or "first" or "second" to get its projection onto one side."#,
+            "The side of the nodes is exported as the \"side\" attribute.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![
+            ParameterType::PositiveInteger,
+            ParameterType::PositiveInteger,
+            ParameterType::Probability,
+            ParameterType::String,
+        ]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let context = "while building a random bipartite generator";
+        let n1 = parameter_values[0].unwrap_usize();
+        let n2 = parameter_values[1].unwrap_usize();
+        let p = parameter_values[2].unwrap_f64();
+        let projection = parameter_values[3]
+            .unwrap_str()
+            .parse::<Projection>()
+            .context(context)?;
+        let n_pairs = n1
+            .checked_mul(n2)
+            .ok_or_else(|| anyhow!("too many nodes"))
+            .context(context)?;
+        Ok(Box::new(move |r| {
+            let edges = erdos_renyi::sample_pair_indices(n_pairs, p, r)
+                .into_iter()
+                .map(|index| (index / n2, index % n2))
+                .collect();
+            build_graph(n1, n2, edges, projection)
+        }))
+    }
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for RandomBipartiteGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+/// A factory used to build generators for random bipartite graphs following the configuration model, with degree sequences sampled from distributions.
+///
+/// Distributions are given by strings, as for [`ConfigurationModelGeneratorFactory`](crate::generators::ConfigurationModelGeneratorFactory).
+/// While the sums of the sampled sequences of the two sides differ, the degree of a random node of the side with the lowest sum is incremented.
+/// Multi-edges are removed, so some nodes may have lower degrees than the sampled ones.
+///
+/// Nodes, sides and projections are handled as in [`RandomBipartiteGeneratorFactory`].
+///
+/// Such factories can be created by passing `bipartite_config/n1,n2,d1,d2,projection` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str) where
+///   - `n1` and `n2` are the sizes of the sides;
+///   - `d1` and `d2` are the degree distributions of the sides;
+///   - `projection` is one of `none`, `first` and `second`.
+#[derive(Default)]
+pub struct BipartiteConfigurationModelGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for BipartiteConfigurationModelGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "bipartite_config"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator producing random bipartite graphs with degrees sampled from distributions.",
+            "First two parameters give the sizes of the sides, while the next two ones give their degree distributions.",
+            r#"Distributions are one of "powerlaw:e:min:max", "poisson:l" and "uniform:min:max"."#,
+            r#"The last parameter is "none" to get the bipartite graph, or "first" or "second" to get its projection onto one side."#,
+            "The side of the nodes is exported as the \"side\" attribute.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![
+            ParameterType::PositiveInteger,
+            ParameterType::PositiveInteger,
+            ParameterType::String,
+            ParameterType::String,
+            ParameterType::String,
+        ]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let context = "while building a bipartite configuration model generator";
+        let n1 = parameter_values[0].unwrap_usize();
+        let n2 = parameter_values[1].unwrap_usize();
+        let distributions = parameter_values[2..4]
+            .iter()
+            .map(|p| p.unwrap_str().parse::<DegreeDistribution>())
+            .collect::<Result<Vec<DegreeDistribution>>>()
+            .context(context)?;
+        let projection = parameter_values[4]
+            .unwrap_str()
+            .parse::<Projection>()
+            .context(context)?;
+        Ok(Box::new(move |r| {
+            let mut degrees1 = distributions[0].sample_sequence(n1, r);
+            let mut degrees2 = distributions[1].sample_sequence(n2, r);
+            balance_sequences(&mut degrees1, &mut degrees2, r);
+            let stubs1 = stubs(&degrees1);
+            let mut stubs2 = stubs(&degrees2);
+            stubs2.shuffle(r);
+            let mut added = HashSet::with_capacity(stubs1.len());
+            let edges = stubs1
+                .into_iter()
+                .zip(stubs2)
+                .filter(|e| added.insert(*e))
+                .collect();
+            build_graph(n1, n2, edges, projection)
+        }))
+    }
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for BipartiteConfigurationModelGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+/// Builds a bipartite graph (or its projection) from its edges, given as pairs of indices in the first and the second side.
+fn build_graph<Ty>(
+    n1: usize,
+    n2: usize,
+    edges: Vec<(NodeIndexType, NodeIndexType)>,
+    projection: Projection,
+) -> Graph<Ty>
+where
+    Ty: EdgeType,
+{
+    match projection {
+        Projection::None => {
+            let mut g = Graph::with_capacity(n1 + n2, edges.len());
+            (0..n1 + n2).for_each(|i| {
+                g.new_node();
+                g.set_node_attribute(
+                    i,
+                    SIDE_ATTRIBUTE,
+                    NodeAttributeValue::PositiveInteger(usize::from(i >= n1)),
+                );
+            });
+            edges.into_iter().for_each(|(i, j)| g.new_edge(i, n1 + j));
+            g
+        }
+        Projection::First => build_projected_graph(n1, n2, 0, edges),
+        Projection::Second => {
+            build_projected_graph(n2, n1, 1, edges.into_iter().map(|(i, j)| (j, i)).collect())
+        }
+    }
+}
+
+/// Builds the projection of a bipartite graph onto one of its sides, given the edges as pairs of indices in the kept and the other side.
+fn build_projected_graph<Ty>(
+    n_kept: usize,
+    n_other: usize,
+    side: usize,
+    edges: Vec<(NodeIndexType, NodeIndexType)>,
+) -> Graph<Ty>
+where
+    Ty: EdgeType,
+{
+    let mut neighbors = vec![vec![]; n_other];
+    edges
+        .into_iter()
+        .for_each(|(kept, other)| neighbors[other].push(kept));
+    let mut projected_edges = neighbors
+        .iter()
+        .flat_map(|kept| {
+            kept.iter().enumerate().flat_map(|(k, i)| {
+                kept[k + 1..]
+                    .iter()
+                    .map(move |j| ((*i).min(*j), (*i).max(*j)))
+            })
+        })
+        .collect::<Vec<(NodeIndexType, NodeIndexType)>>();
+    projected_edges.sort_unstable();
+    projected_edges.dedup();
+    let mut g = Graph::with_capacity(n_kept, projected_edges.len());
+    (0..n_kept).for_each(|i| {
+        g.new_node();
+        g.set_node_attribute(i, SIDE_ATTRIBUTE, NodeAttributeValue::PositiveInteger(side));
+    });
+    projected_edges.into_iter().for_each(|(i, j)| {
+        g.new_edge(i, j);
+        if Ty::is_directed() {
+            g.new_edge(j, i);
+        }
+    });
+    g
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::{Directed, Undirected};
+    use rand::{rngs::ThreadRng, SeedableRng};
+    use rand_pcg::Pcg32;
+
+    fn params(n1: usize, n2: usize, p: f64, projection: &str) -> Vec<ParameterValue> {
+        vec![
+            ParameterValue::PositiveInteger(n1),
+            ParameterValue::PositiveInteger(n2),
+            ParameterValue::Probability(p),
+            ParameterValue::String(projection.to_string()),
+        ]
+    }
+
+    #[test]
+    fn test_unknown_projection() {
+        assert!(
+            (RandomBipartiteGeneratorFactory.try_with_params(params(3, 2, 0.5, "third"))
+                as Result<BoxedGenerator<Undirected, ThreadRng>>)
+                .is_err()
+        )
+    }
+
+    #[test]
+    fn test_complete() {
+        let g: Graph<Directed> = RandomBipartiteGeneratorFactory
+            .try_with_params(params(2, 2, 1., "none"))
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(
+            vec![(0, 2), (0, 3), (1, 2), (1, 3)],
+            g.iter_edges()
+                .collect::<Vec<(NodeIndexType, NodeIndexType)>>()
+        );
+        assert_eq!(
+            Some(&NodeAttributeValue::PositiveInteger(1)),
+            g.node_attributes(2).get(SIDE_ATTRIBUTE)
+        );
+    }
+
+    #[test]
+    fn test_edges_between_sides() {
+        let g: Graph<Undirected> = RandomBipartiteGeneratorFactory
+            .try_with_params(params(30, 20, 0.2, "none"))
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(50, g.n_nodes());
+        assert!(g.iter_edges().all(|(i, j)| i < 30 && j >= 30));
+    }
+
+    #[test]
+    fn test_projection() {
+        let g: Graph<Undirected> = build_graph(
+            3,
+            2,
+            vec![(0, 0), (1, 0), (1, 1), (2, 1)],
+            Projection::First,
+        );
+        assert_eq!(3, g.n_nodes());
+        assert_eq!(
+            vec![(0, 1), (1, 2)],
+            g.iter_edges()
+                .collect::<Vec<(NodeIndexType, NodeIndexType)>>()
+        );
+        let g: Graph<Directed> = build_graph(
+            3,
+            2,
+            vec![(0, 0), (1, 0), (1, 1), (2, 1)],
+            Projection::Second,
+        );
+        assert_eq!(2, g.n_nodes());
+        assert_eq!(
+            vec![(0, 1), (1, 0)],
+            g.iter_edges()
+                .collect::<Vec<(NodeIndexType, NodeIndexType)>>()
+        );
+        assert_eq!(
+            Some(&NodeAttributeValue::PositiveInteger(1)),
+            g.node_attributes(0).get(SIDE_ATTRIBUTE)
+        );
+    }
+
+    #[test]
+    fn test_config_degrees() {
+        let g: Graph<Undirected> = BipartiteConfigurationModelGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(10),
+                ParameterValue::PositiveInteger(20),
+                ParameterValue::String("uniform:4:4".to_string()),
+                ParameterValue::String("uniform:2:2".to_string()),
+                ParameterValue::String("none".to_string()),
+            ])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(30, g.n_nodes());
+        let mut edges = g
+            .iter_edges()
+            .collect::<Vec<(NodeIndexType, NodeIndexType)>>();
+        assert!(edges.iter().all(|(i, j)| *i < 10 && *j >= 10));
+        edges.sort_unstable();
+        edges.dedup();
+        assert_eq!(g.n_edges(), edges.len());
+        assert!(g.n_edges() <= 40);
+    }
+
+    #[test]
+    fn test_config_empty_side() {
+        let g: Graph<Undirected> = BipartiteConfigurationModelGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(0),
+                ParameterValue::PositiveInteger(5),
+                ParameterValue::String("uniform:1:3".to_string()),
+                ParameterValue::String("uniform:1:3".to_string()),
+                ParameterValue::String("second".to_string()),
+            ])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(5, g.n_nodes());
+        assert_eq!(0, g.n_edges());
+    }
+}
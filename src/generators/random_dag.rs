@@ -0,0 +1,186 @@
+use super::{BoxedGenerator, GeneratorFactory};
+use crate::{Graph, NamedParam, NodeIndexType, ParameterType, ParameterValue};
+use anyhow::Result;
+use petgraph::Directed;
+use rand::{
+    seq::{index, SliceRandom},
+    Rng,
+};
+
+/// A factory used to build generators for random directed acyclic graphs.
+///
+/// A random topological order of the nodes is first drawn; then, for each pair of nodes, an edge going from the first node to the second one (according to the order) is added with probability `p`.
+/// The graphs are thus the acyclic orientations of Erdős–Rényi graphs.
+///
+/// This generator is only available for directed graphs.
+///
+/// Such factories can be created by passing `dag/n,p` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str) where
+///   - `n` is the size of graph to produce;
+///   - `p` is the probability each edge appears in the graph.
+#[derive(Default)]
+pub struct RandomDagGeneratorFactory;
+
+impl<R> NamedParam<BoxedGenerator<Directed, R>> for RandomDagGeneratorFactory
+where
+    R: Rng,
+{
+    fn name(&self) -> &'static str {
+        "dag"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator producing random directed acyclic graphs.",
+            "First parameter gives the number of nodes of the graph, while the second one gives the probability each edge compatible with a random topological order appears in the graph.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![ParameterType::PositiveInteger, ParameterType::Probability]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Directed, R>> {
+        let n = parameter_values[0].unwrap_usize();
+        let p = parameter_values[1].unwrap_f64();
+        Ok(Box::new(move |r| {
+            let order = random_order(n, r);
+            let mut g = Graph::with_capacity(n, 0);
+            (0..n).for_each(|_| g.new_node());
+            (0..n).for_each(|i| {
+                (i + 1..n).for_each(|j| {
+                    if r.gen_bool(p) {
+                        g.new_edge(order[i], order[j]);
+                    }
+                })
+            });
+            g
+        }))
+    }
+}
+
+impl<R> GeneratorFactory<Directed, R> for RandomDagGeneratorFactory where R: Rng {}
+
+/// A factory used to build generators for random directed acyclic graphs with a fixed out-degree.
+///
+/// A random topological order of the nodes is first drawn; then, each node is linked to `d` distinct nodes chosen uniformly among the ones that follow it in the order.
+/// Since the last nodes of the order have less than `d` successors, they are linked to all of them.
+///
+/// This generator is only available for directed graphs.
+///
+/// Such factories can be created by passing `dag_out/n,d` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str) where
+///   - `n` is the size of graph to produce;
+///   - `d` is the out-degree of the nodes.
+#[derive(Default)]
+pub struct RandomDagOutDegreeGeneratorFactory;
+
+impl<R> NamedParam<BoxedGenerator<Directed, R>> for RandomDagOutDegreeGeneratorFactory
+where
+    R: Rng,
+{
+    fn name(&self) -> &'static str {
+        "dag_out"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator producing random directed acyclic graphs with a fixed out-degree.",
+            "First parameter gives the number of nodes of the graph, while the second one gives the out-degree of the nodes.",
+            "Nodes at the end of the topological order have a lower out-degree.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![
+            ParameterType::PositiveInteger,
+            ParameterType::PositiveInteger,
+        ]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Directed, R>> {
+        let n = parameter_values[0].unwrap_usize();
+        let d = parameter_values[1].unwrap_usize();
+        Ok(Box::new(move |r| {
+            let order = random_order(n, r);
+            let mut g = Graph::with_capacity(n, n * d);
+            (0..n).for_each(|_| g.new_node());
+            (0..n).for_each(|i| {
+                let n_successors = n - i - 1;
+                let mut successors = index::sample(r, n_successors, d.min(n_successors)).into_vec();
+                successors.sort_unstable();
+                successors
+                    .into_iter()
+                    .for_each(|j| g.new_edge(order[i], order[i + 1 + j]));
+            });
+            g
+        }))
+    }
+}
+
+impl<R> GeneratorFactory<Directed, R> for RandomDagOutDegreeGeneratorFactory where R: Rng {}
+
+pub(super) fn random_order<R>(n: usize, r: &mut R) -> Vec<NodeIndexType>
+where
+    R: Rng,
+{
+    let mut order = (0..n).collect::<Vec<NodeIndexType>>();
+    order.shuffle(r);
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::algo::is_cyclic_directed;
+    use rand::SeedableRng;
+    use rand_pcg::Pcg32;
+
+    #[test]
+    fn test_dag_complete() {
+        let g: Graph<Directed> = RandomDagGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(10),
+                ParameterValue::Probability(1.),
+            ])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(10, g.n_nodes());
+        assert_eq!(45, g.n_edges());
+        assert!(!is_cyclic_directed(g.petgraph()));
+    }
+
+    #[test]
+    fn test_dag_acyclic() {
+        let g: Graph<Directed> = RandomDagGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(100),
+                ParameterValue::Probability(0.3),
+            ])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(100, g.n_nodes());
+        assert!(g.n_edges() > 0);
+        assert!(!is_cyclic_directed(g.petgraph()));
+    }
+
+    #[test]
+    fn test_dag_out_degrees() {
+        let g: Graph<Directed> = RandomDagOutDegreeGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(20),
+                ParameterValue::PositiveInteger(3),
+            ])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(20, g.n_nodes());
+        assert!(!is_cyclic_directed(g.petgraph()));
+        let mut out_degrees = vec![0; 20];
+        g.iter_edges().for_each(|(i, _)| out_degrees[i] += 1);
+        out_degrees.sort_unstable();
+        let mut expected = vec![0, 1, 2];
+        expected.append(&mut vec![3; 17]);
+        assert_eq!(expected, out_degrees);
+    }
+}
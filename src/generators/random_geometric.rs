@@ -0,0 +1,261 @@
+use super::{BoxedGenerator, GeneratorFactory, POSITION_ATTRIBUTE};
+use crate::{Graph, NamedParam, NodeAttributeValue, ParameterType, ParameterValue};
+use anyhow::{anyhow, Context, Result};
+use petgraph::EdgeType;
+use rand::Rng;
+use std::collections::HashMap;
+
+/// A factory used to build generators for [random geometric graphs](https://en.wikipedia.org/wiki/Random_geometric_graph).
+///
+/// Nodes are placed uniformly at random in the unit hypercube, and two nodes are linked if their Euclidean distance is at most `r`.
+/// Nodes are bucketed in a grid which cells are at least as large as `r`, so only pairs of nodes in neighbor cells are checked;
+/// for small radii, the time complexity is near-linear in the number of nodes and edges.
+///
+/// In directed graphs generated by this objects, linked nodes are connected by two edges, one in each direction.
+///
+/// The coordinates of each node are stored as its `pos` attribute.
+///
+/// Such factories can be created by passing `rgg/n,r,dim` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str) where
+///   - `n` is the size of graph to produce;
+///   - `r` is the radius under which nodes are linked;
+///   - `dim` is the dimension of the space.
+///
+/// The dimension must be higher than zero.
+#[derive(Default)]
+pub struct RandomGeometricGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for RandomGeometricGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "rgg"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator producing random geometric graphs in the unit hypercube.",
+            "First parameter gives the number of nodes, the second one the radius under which nodes are linked, and the third one the dimension of the space.",
+            "Node coordinates are exported as the \"pos\" attribute.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![
+            ParameterType::PositiveInteger,
+            ParameterType::PositiveFloat,
+            ParameterType::PositiveInteger,
+        ]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let n = parameter_values[0].unwrap_usize();
+        let radius = parameter_values[1].unwrap_f64();
+        let dim = parameter_values[2].unwrap_usize();
+        if dim == 0 {
+            return Err(anyhow!(r#"third parameter ("dim") must be higher than 0"#))
+                .context("while building a random geometric graph generator");
+        }
+        Ok(Box::new(move |r| build_graph(n, radius, dim, r)))
+    }
+}
+
+fn build_graph<Ty, R>(n: usize, radius: f64, dim: usize, r: &mut R) -> Graph<Ty>
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    let positions = (0..n)
+        .map(|_| (0..dim).map(|_| r.gen::<f64>()).collect())
+        .collect::<Vec<Vec<f64>>>();
+    let max_cells_per_dim = (n as f64).powf(1. / dim as f64).floor().max(1.);
+    let cells_per_dim = if radius > 0. {
+        (1. / radius).floor().clamp(1., max_cells_per_dim) as usize
+    } else {
+        max_cells_per_dim as usize
+    };
+    let cell_of = |p: &[f64]| {
+        p.iter()
+            .map(|x| ((x * cells_per_dim as f64) as usize).min(cells_per_dim - 1))
+            .collect::<Vec<usize>>()
+    };
+    let mut cells: HashMap<Vec<usize>, Vec<usize>> = HashMap::new();
+    positions
+        .iter()
+        .enumerate()
+        .for_each(|(i, p)| cells.entry(cell_of(p)).or_default().push(i));
+    let mut g = Graph::with_capacity(n, 0);
+    (0..n).for_each(|_| g.new_node());
+    let squared_radius = radius * radius;
+    positions.iter().enumerate().for_each(|(i, p)| {
+        let mut neighbors = neighbor_cells(&cell_of(p), cells_per_dim)
+            .iter()
+            .filter_map(|c| cells.get(c))
+            .flatten()
+            .copied()
+            .filter(|j| *j > i && squared_distance(p, &positions[*j]) <= squared_radius)
+            .collect::<Vec<usize>>();
+        neighbors.sort_unstable();
+        neighbors.into_iter().for_each(|j| {
+            g.new_edge(i, j);
+            if Ty::is_directed() {
+                g.new_edge(j, i);
+            }
+        });
+    });
+    positions.into_iter().enumerate().for_each(|(i, p)| {
+        g.set_node_attribute(i, POSITION_ATTRIBUTE, NodeAttributeValue::FloatList(p))
+    });
+    g
+}
+
+fn neighbor_cells(cell: &[usize], cells_per_dim: usize) -> Vec<Vec<usize>> {
+    cell.iter().fold(vec![vec![]], |prefixes, c| {
+        let range = c.saturating_sub(1)..=(c + 1).min(cells_per_dim - 1);
+        prefixes
+            .into_iter()
+            .flat_map(|prefix| {
+                range.clone().map(move |x| {
+                    let mut v = prefix.clone();
+                    v.push(x);
+                    v
+                })
+            })
+            .collect()
+    })
+}
+
+fn squared_distance(p1: &[f64], p2: &[f64]) -> f64 {
+    p1.iter()
+        .zip(p2.iter())
+        .map(|(x, y)| (x - y) * (x - y))
+        .sum()
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for RandomGeometricGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NodeIndexType;
+    use petgraph::{Directed, Undirected};
+    use rand::{rngs::ThreadRng, SeedableRng};
+    use rand_pcg::Pcg32;
+
+    fn positions<Ty>(g: &Graph<Ty>) -> Vec<Vec<f64>>
+    where
+        Ty: EdgeType,
+    {
+        (0..g.n_nodes())
+            .map(|i| match g.node_attributes(i).get(POSITION_ATTRIBUTE) {
+                Some(NodeAttributeValue::FloatList(p)) => p.clone(),
+                _ => panic!(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_zero_dimension() {
+        assert!((RandomGeometricGeneratorFactory.try_with_params(vec![
+            ParameterValue::PositiveInteger(10),
+            ParameterValue::PositiveFloat(0.1),
+            ParameterValue::PositiveInteger(0),
+        ]) as Result<BoxedGenerator<Directed, ThreadRng>>)
+            .is_err())
+    }
+
+    #[test]
+    fn test_same_as_naive() {
+        for dim in 1..=3 {
+            let g: Graph<Undirected> = RandomGeometricGeneratorFactory
+                .try_with_params(vec![
+                    ParameterValue::PositiveInteger(200),
+                    ParameterValue::PositiveFloat(0.15),
+                    ParameterValue::PositiveInteger(dim),
+                ])
+                .unwrap()(&mut Pcg32::seed_from_u64(0));
+            assert_eq!(200, g.n_nodes());
+            let positions = positions(&g);
+            positions.iter().for_each(|p| assert_eq!(dim, p.len()));
+            let expected = (0..200)
+                .flat_map(|i| (i + 1..200).map(move |j| (i, j)))
+                .filter(|(i, j)| squared_distance(&positions[*i], &positions[*j]) <= 0.15 * 0.15)
+                .collect::<Vec<(NodeIndexType, NodeIndexType)>>();
+            assert!(!expected.is_empty());
+            assert_eq!(
+                expected,
+                g.iter_edges()
+                    .collect::<Vec<(NodeIndexType, NodeIndexType)>>()
+            );
+        }
+    }
+
+    #[test]
+    fn test_large_radius() {
+        let g: Graph<Directed> = RandomGeometricGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(5),
+                ParameterValue::PositiveFloat(2.),
+                ParameterValue::PositiveInteger(3),
+            ])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(5, g.n_nodes());
+        assert_eq!(20, g.n_edges());
+    }
+
+    #[test]
+    fn test_zero_radius() {
+        let g: Graph<Undirected> = RandomGeometricGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(100),
+                ParameterValue::PositiveFloat(0.),
+                ParameterValue::PositiveInteger(2),
+            ])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(100, g.n_nodes());
+        assert_eq!(0, g.n_edges());
+    }
+
+    #[test]
+    fn test_set_position_replaces_value() {
+        let mut g: Graph<Directed> = Graph::default();
+        g.new_node();
+        g.set_node_attribute(
+            0,
+            POSITION_ATTRIBUTE,
+            NodeAttributeValue::FloatList(vec![0.5, 1.]),
+        );
+        g.set_node_attribute(0, "community", NodeAttributeValue::PositiveInteger(0));
+        g.set_node_attribute(
+            0,
+            POSITION_ATTRIBUTE,
+            NodeAttributeValue::FloatList(vec![0.25]),
+        );
+        assert_eq!(
+            vec![
+                (
+                    POSITION_ATTRIBUTE,
+                    &NodeAttributeValue::FloatList(vec![0.25])
+                ),
+                ("community", &NodeAttributeValue::PositiveInteger(0))
+            ],
+            g.node_attributes(0).iter().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            "0.25",
+            g.node_attributes(0)
+                .get(POSITION_ATTRIBUTE)
+                .unwrap()
+                .to_string()
+        );
+    }
+}
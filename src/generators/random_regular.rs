@@ -0,0 +1,394 @@
+use super::{BoxedGenerator, GeneratorFactory};
+use crate::{Graph, NamedParam, NodeIndexType, ParameterType, ParameterValue};
+use anyhow::{anyhow, Context, Result};
+use petgraph::EdgeType;
+use rand::{seq::SliceRandom, Rng};
+use std::collections::HashSet;
+
+/// A factory used to build generators for random [regular graphs](https://en.wikipedia.org/wiki/Regular_graph).
+///
+/// When `d` is greater than `(n-1)/2`, a random `(n-1-d)`-regular graph is built and its complement is returned; this does not change the distribution.
+///
+/// For small degrees, graphs are sampled using the pairing model: stubs are paired at random, and the whole pairing restarts as soon as a self-loop or a multi-edge appears.
+/// The graphs are then uniformly distributed among the simple regular graphs.
+/// For higher degrees, such pairings would almost never succeed; in this case, many random switches are applied to a circulant regular graph.
+/// This switch Markov chain converges to the uniform distribution, so the graphs are approximately uniformly distributed.
+///
+/// In directed graphs generated with this object, each node has exactly `d` incoming edges and `d` outgoing edges, and there is no self-loop nor multi-edge.
+///
+/// Such factories can be created by passing `regular/n,d` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str) where
+///   - `n` is the size of graph to produce;
+///   - `d` is the degree of the nodes.
+///
+/// Parameter `d` must be lower than `n`; for undirected graphs, `n*d` must also be even.
+#[derive(Default)]
+pub struct RandomRegularGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for RandomRegularGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "regular"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator producing random regular graphs.",
+            "First parameter gives the number of nodes, while the second one gives the degree of the nodes.",
+            "In directed graphs, both the incoming and the outgoing degrees are equal to the second parameter.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![
+            ParameterType::PositiveInteger,
+            ParameterType::PositiveInteger,
+        ]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let context = "while building a random regular graph generator";
+        let n = parameter_values[0].unwrap_usize();
+        let d = parameter_values[1].unwrap_usize();
+        if d >= n {
+            return Err(anyhow!(
+                r#"second parameter ("d") must be lower than the first one ("n")"#
+            ))
+            .context(context);
+        }
+        if !Ty::is_directed() && (n * d) & 1 == 1 {
+            return Err(anyhow!(
+                r#"the product of the parameters ("n*d") must be even"#
+            ))
+            .context(context);
+        }
+        Ok(Box::new(move |r| build_graph(n, d, r)))
+    }
+}
+
+/// The highest logarithm of the expected number of pairings drawn before getting a simple graph for which the pairing model is used.
+const MAX_LOG_EXPECTED_PAIRINGS: f64 = 6.;
+
+/// The number of switches applied per edge when the switch chain is used.
+const SWITCHES_PER_EDGE: usize = 20;
+
+fn build_graph<Ty, R>(n: usize, d: usize, r: &mut R) -> Graph<Ty>
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    let directed = Ty::is_directed();
+    let complement = 2 * d > n - 1;
+    let sparse_degree = if complement { n - 1 - d } else { d };
+    let log_expected_pairings = if directed {
+        sparse_degree as f64 + (sparse_degree as f64 - 1.).powi(2) / 2.
+    } else {
+        ((sparse_degree * sparse_degree) as f64 - 1.) / 4.
+    };
+    let sparse_edges = if log_expected_pairings <= MAX_LOG_EXPECTED_PAIRINGS {
+        loop {
+            if let Some(edges) = try_pairing(n, sparse_degree, directed, r) {
+                break edges;
+            }
+        }
+    } else {
+        switch_chain(n, sparse_degree, directed, r)
+    };
+    let mut edges = if complement {
+        (0..n)
+            .flat_map(|i| (if directed { 0 } else { i + 1 }..n).map(move |j| (i, j)))
+            .filter(|(i, j)| i != j && !sparse_edges.contains(&(*i, *j)))
+            .collect::<Vec<(NodeIndexType, NodeIndexType)>>()
+    } else {
+        sparse_edges
+            .into_iter()
+            .collect::<Vec<(NodeIndexType, NodeIndexType)>>()
+    };
+    edges.sort_unstable();
+    let mut g = Graph::with_capacity(n, edges.len());
+    (0..n).for_each(|_| g.new_node());
+    edges.into_iter().for_each(|(i, j)| g.new_edge(i, j));
+    g
+}
+
+fn edge_key(i: NodeIndexType, j: NodeIndexType, directed: bool) -> (NodeIndexType, NodeIndexType) {
+    if directed {
+        (i, j)
+    } else {
+        (i.min(j), i.max(j))
+    }
+}
+
+/// Pairs the stubs of the nodes at random, returning `None` as soon as a self-loop or a multi-edge appears.
+fn try_pairing<R>(
+    n: usize,
+    d: usize,
+    directed: bool,
+    r: &mut R,
+) -> Option<HashSet<(NodeIndexType, NodeIndexType)>>
+where
+    R: Rng,
+{
+    let mut edges = HashSet::with_capacity(n * d);
+    let mut stubs = (0..n)
+        .flat_map(|i| std::iter::repeat_n(i, d))
+        .collect::<Vec<NodeIndexType>>();
+    stubs.shuffle(r);
+    let pairs = if directed {
+        (0..n * d)
+            .map(|k| (k / d, stubs[k]))
+            .collect::<Vec<(NodeIndexType, NodeIndexType)>>()
+    } else {
+        stubs
+            .chunks_exact(2)
+            .map(|pair| (pair[0], pair[1]))
+            .collect()
+    };
+    for (i, j) in pairs {
+        if i == j || !edges.insert(edge_key(i, j, directed)) {
+            return None;
+        }
+    }
+    Some(edges)
+}
+
+/// Applies random switches to a circulant regular graph.
+///
+/// A switch replaces two edges `(a,b)` and `(c,d)` by `(a,d)` and `(c,b)`, unless this creates a self-loop or a multi-edge.
+fn switch_chain<R>(
+    n: usize,
+    d: usize,
+    directed: bool,
+    r: &mut R,
+) -> HashSet<(NodeIndexType, NodeIndexType)>
+where
+    R: Rng,
+{
+    let mut edges = if directed {
+        (0..n)
+            .flat_map(|i| (1..=d).map(move |k| (i, (i + k) % n)))
+            .collect::<Vec<(NodeIndexType, NodeIndexType)>>()
+    } else {
+        let mut edges = (0..n)
+            .flat_map(|i| (1..=d / 2).map(move |k| (i, (i + k) % n)))
+            .collect::<Vec<(NodeIndexType, NodeIndexType)>>();
+        if d & 1 == 1 {
+            edges.extend((0..n / 2).map(|i| (i, i + n / 2)));
+        }
+        edges
+    };
+    let mut edge_set = edges
+        .iter()
+        .map(|(i, j)| edge_key(*i, *j, directed))
+        .collect::<HashSet<(NodeIndexType, NodeIndexType)>>();
+    if edges.len() < 2 {
+        return edge_set;
+    }
+    for _ in 0..SWITCHES_PER_EDGE * edges.len() {
+        let (e1, e2) = (r.gen_range(0..edges.len()), r.gen_range(0..edges.len()));
+        let (a, b) = edges[e1];
+        let (c, d) = if !directed && r.gen() {
+            (edges[e2].1, edges[e2].0)
+        } else {
+            edges[e2]
+        };
+        let (k1, k2) = (edge_key(a, d, directed), edge_key(c, b, directed));
+        if a == d || c == b || k1 == k2 || edge_set.contains(&k1) || edge_set.contains(&k2) {
+            continue;
+        }
+        edge_set.remove(&edge_key(a, b, directed));
+        edge_set.remove(&edge_key(c, d, directed));
+        edge_set.insert(k1);
+        edge_set.insert(k2);
+        edges[e1] = (a, d);
+        edges[e2] = (c, b);
+    }
+    edge_set
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for RandomRegularGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::{Directed, Undirected};
+    use rand::{rngs::ThreadRng, SeedableRng};
+    use rand_pcg::Pcg32;
+
+    #[test]
+    fn test_odd_product() {
+        assert!((RandomRegularGeneratorFactory.try_with_params(vec![
+            ParameterValue::PositiveInteger(5),
+            ParameterValue::PositiveInteger(3)
+        ]) as Result<BoxedGenerator<Undirected, ThreadRng>>)
+            .is_err())
+    }
+
+    #[test]
+    fn test_odd_product_directed() {
+        assert!((RandomRegularGeneratorFactory.try_with_params(vec![
+            ParameterValue::PositiveInteger(5),
+            ParameterValue::PositiveInteger(3)
+        ]) as Result<BoxedGenerator<Directed, ThreadRng>>)
+            .is_ok())
+    }
+
+    #[test]
+    fn test_d_not_lower_than_n() {
+        assert!((RandomRegularGeneratorFactory.try_with_params(vec![
+            ParameterValue::PositiveInteger(4),
+            ParameterValue::PositiveInteger(4)
+        ]) as Result<BoxedGenerator<Undirected, ThreadRng>>)
+            .is_err())
+    }
+
+    #[test]
+    fn test_zero_degree() {
+        let g: Graph<Undirected> = RandomRegularGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(4),
+                ParameterValue::PositiveInteger(0),
+            ])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(4, g.n_nodes());
+        assert_eq!(0, g.n_edges());
+    }
+
+    #[test]
+    fn test_regular_undirected() {
+        let mut rng = Pcg32::seed_from_u64(0);
+        let generator = RandomRegularGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(20),
+                ParameterValue::PositiveInteger(5),
+            ])
+            .unwrap();
+        for _ in 0..10 {
+            let g: Graph<Undirected> = generator(&mut rng);
+            let mut degrees = vec![0; 20];
+            let mut edges = HashSet::new();
+            g.iter_edges().for_each(|(i, j)| {
+                assert_ne!(i, j);
+                assert!(edges.insert((i.min(j), i.max(j))));
+                degrees[i] += 1;
+                degrees[j] += 1;
+            });
+            assert_eq!(vec![5; 20], degrees);
+        }
+    }
+
+    #[test]
+    fn test_regular_directed() {
+        let mut rng = Pcg32::seed_from_u64(0);
+        let generator = RandomRegularGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(7),
+                ParameterValue::PositiveInteger(6),
+            ])
+            .unwrap();
+        let g: Graph<Directed> = generator(&mut rng);
+        let mut edges = g
+            .iter_edges()
+            .collect::<Vec<(NodeIndexType, NodeIndexType)>>();
+        edges.sort_unstable();
+        let expected = (0..7)
+            .flat_map(|i| (0..7).filter(move |j| i != *j).map(move |j| (i, j)))
+            .collect::<Vec<(NodeIndexType, NodeIndexType)>>();
+        assert_eq!(expected, edges);
+    }
+
+    #[test]
+    fn test_regular_dense() {
+        let mut rng = Pcg32::seed_from_u64(0);
+        for (n, d) in [(100, 97), (200, 190), (31, 16)] {
+            let g: Graph<Undirected> = RandomRegularGeneratorFactory
+                .try_with_params(vec![
+                    ParameterValue::PositiveInteger(n),
+                    ParameterValue::PositiveInteger(d),
+                ])
+                .unwrap()(&mut rng);
+            let mut degrees = vec![0; n];
+            let mut edges = HashSet::new();
+            g.iter_edges().for_each(|(i, j)| {
+                assert_ne!(i, j);
+                assert!(edges.insert((i.min(j), i.max(j))));
+                degrees[i] += 1;
+                degrees[j] += 1;
+            });
+            assert_eq!(vec![d; n], degrees);
+        }
+    }
+
+    #[test]
+    fn test_regular_dense_directed() {
+        let mut rng = Pcg32::seed_from_u64(0);
+        let g: Graph<Directed> = RandomRegularGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(300),
+                ParameterValue::PositiveInteger(290),
+            ])
+            .unwrap()(&mut rng);
+        let mut in_degrees = vec![0; 300];
+        let mut out_degrees = vec![0; 300];
+        let mut edges = HashSet::new();
+        g.iter_edges().for_each(|(i, j)| {
+            assert_ne!(i, j);
+            assert!(edges.insert((i, j)));
+            out_degrees[i] += 1;
+            in_degrees[j] += 1;
+        });
+        assert_eq!(vec![290; 300], in_degrees);
+        assert_eq!(vec![290; 300], out_degrees);
+    }
+
+    /// Returns the proportion of 2-regular graphs on 6 nodes made of two triangles, which is 1/7 under the uniform distribution (the other ones are hexagons).
+    fn two_triangles_rate<F>(mut sample: F) -> f64
+    where
+        F: FnMut() -> HashSet<(NodeIndexType, NodeIndexType)>,
+    {
+        let n_samples = 20_000;
+        let n_two_triangles = (0..n_samples)
+            .filter(|_| {
+                let edges = sample();
+                let neighbors = (1..6)
+                    .filter(|i| edges.contains(&(0, *i)))
+                    .collect::<Vec<NodeIndexType>>();
+                edges.contains(&(neighbors[0], neighbors[1]))
+            })
+            .count();
+        n_two_triangles as f64 / n_samples as f64
+    }
+
+    #[test]
+    fn test_pairing_distribution() {
+        let mut rng = Pcg32::seed_from_u64(0);
+        let generator = RandomRegularGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(6),
+                ParameterValue::PositiveInteger(2),
+            ])
+            .unwrap();
+        let rate = two_triangles_rate(|| {
+            let g: Graph<Undirected> = generator(&mut rng);
+            g.iter_edges().map(|(i, j)| (i.min(j), i.max(j))).collect()
+        });
+        assert!((rate - 1. / 7.).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_switch_chain_distribution() {
+        let mut rng = Pcg32::seed_from_u64(0);
+        let rate = two_triangles_rate(|| switch_chain(6, 2, false, &mut rng));
+        assert!((rate - 1. / 7.).abs() < 0.01);
+    }
+}
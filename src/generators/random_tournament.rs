@@ -0,0 +1,89 @@
+use super::{random_dag::random_order, BoxedGenerator, GeneratorFactory};
+use crate::{Graph, NamedParam, ParameterType, ParameterValue};
+use anyhow::Result;
+use petgraph::Directed;
+use rand::Rng;
+
+/// A factory used to build generators for random transitive [tournaments](https://en.wikipedia.org/wiki/Tournament_(graph_theory)).
+///
+/// A random order of the nodes is first drawn; then, each pair of nodes is linked by exactly one edge, going from the first node to the second one according to this order.
+/// The tournaments are thus acyclic.
+///
+/// This generator is only available for directed graphs.
+///
+/// Such factories can be created by passing `tournament/n` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str),
+/// where `n` is the number of nodes.
+#[derive(Default)]
+pub struct RandomTournamentGeneratorFactory;
+
+impl<R> NamedParam<BoxedGenerator<Directed, R>> for RandomTournamentGeneratorFactory
+where
+    R: Rng,
+{
+    fn name(&self) -> &'static str {
+        "tournament"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator producing random transitive (hence acyclic) tournaments.",
+            "The first parameter gives the number of nodes.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![ParameterType::PositiveInteger]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Directed, R>> {
+        let n = parameter_values[0].unwrap_usize();
+        Ok(Box::new(move |r| {
+            let order = random_order(n, r);
+            let mut g = Graph::with_capacity(n, n * n.saturating_sub(1) / 2);
+            (0..n).for_each(|_| g.new_node());
+            (0..n).for_each(|i| (i + 1..n).for_each(|j| g.new_edge(order[i], order[j])));
+            g
+        }))
+    }
+}
+
+impl<R> GeneratorFactory<Directed, R> for RandomTournamentGeneratorFactory where R: Rng {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::algo::is_cyclic_directed;
+    use rand::SeedableRng;
+    use rand_pcg::Pcg32;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_tournament() {
+        let g: Graph<Directed> = RandomTournamentGeneratorFactory
+            .try_with_params(vec![ParameterValue::PositiveInteger(10)])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(10, g.n_nodes());
+        assert_eq!(45, g.n_edges());
+        let pairs = g
+            .iter_edges()
+            .map(|(i, j)| (i.min(j), i.max(j)))
+            .collect::<HashSet<(usize, usize)>>();
+        assert_eq!(45, pairs.len());
+    }
+
+    #[test]
+    fn test_tournament_acyclic() {
+        let mut rng = Pcg32::seed_from_u64(0);
+        let generator = RandomTournamentGeneratorFactory
+            .try_with_params(vec![ParameterValue::PositiveInteger(50)])
+            .unwrap();
+        for _ in 0..10 {
+            let g: Graph<Directed> = generator(&mut rng);
+            assert_eq!(1225, g.n_edges());
+            assert!(!is_cyclic_directed(g.petgraph()));
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use super::{BoxedGenerator, GeneratorFactory};
+use crate::{Graph, NamedParam, NodeIndexType, ParameterType, ParameterValue};
+use anyhow::Result;
+use petgraph::EdgeType;
+use rand::Rng;
+use std::collections::VecDeque;
+
+/// A factory used to build generators for uniformly random labelled trees.
+///
+/// Trees are sampled uniformly among the `n^(n-2)` labelled trees of `n` nodes, by decoding a random [Prüfer sequence](https://en.wikipedia.org/wiki/Pr%C3%BCfer_sequence) in linear time.
+///
+/// In directed graphs generated by this objects, the tree is rooted at node 0, and all edges follow paths from the root to the leaves.
+///
+/// Such factories can be created by passing `uniform_tree/n` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str),
+/// where `n` is the number of nodes and must be at least 0.
+#[derive(Default)]
+pub struct UniformTreeGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for UniformTreeGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "uniform_tree"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator producing trees chosen uniformly at random among labelled trees.",
+            "The first parameter gives the number of nodes.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![ParameterType::PositiveInteger]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let n = parameter_values[0].unwrap_usize();
+        Ok(Box::new(move |r| {
+            let mut g = Graph::with_capacity(n, n.saturating_sub(1));
+            (0..n).for_each(|_| g.new_node());
+            if n >= 2 {
+                let sequence = (0..n - 2)
+                    .map(|_| r.gen_range(0..n))
+                    .collect::<Vec<NodeIndexType>>();
+                rooted_edges(n, &decode_pruefer_sequence(&sequence))
+                    .into_iter()
+                    .for_each(|(i, j)| g.new_edge(i, j));
+            }
+            g
+        }))
+    }
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for UniformTreeGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+/// A factory used to build generators for [random recursive trees](https://en.wikipedia.org/wiki/Recursive_tree).
+///
+/// Nodes are added one at a time, and each new node is linked to a node chosen uniformly at random among the existing ones.
+///
+/// In directed graphs generated by this objects, edges go from the existing nodes to the new ones, so that all edges follow paths from the root (node 0) to the leaves.
+///
+/// Such factories can be created by passing `recursive_tree/n` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str),
+/// where `n` is the number of nodes and must be at least 0.
+#[derive(Default)]
+pub struct RecursiveTreeGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for RecursiveTreeGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "recursive_tree"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator producing random recursive trees.",
+            "The first parameter gives the number of nodes.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![ParameterType::PositiveInteger]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let n = parameter_values[0].unwrap_usize();
+        Ok(Box::new(move |r| {
+            let mut g = Graph::with_capacity(n, n.saturating_sub(1));
+            (0..n).for_each(|_| g.new_node());
+            (1..n).for_each(|i| g.new_edge(r.gen_range(0..i), i));
+            g
+        }))
+    }
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for RecursiveTreeGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+/// Decodes a Prüfer sequence of length `n-2` into the `n-1` edges of a tree.
+fn decode_pruefer_sequence(sequence: &[NodeIndexType]) -> Vec<(NodeIndexType, NodeIndexType)> {
+    let n = sequence.len() + 2;
+    let mut degrees = vec![1; n];
+    sequence.iter().for_each(|i| degrees[*i] += 1);
+    let mut edges = Vec::with_capacity(n - 1);
+    let mut pointer = degrees.iter().position(|d| *d == 1).unwrap();
+    let mut leaf = pointer;
+    for v in sequence.iter().copied() {
+        edges.push((leaf, v));
+        degrees[v] -= 1;
+        if degrees[v] == 1 && v < pointer {
+            leaf = v;
+        } else {
+            pointer += 1;
+            while degrees[pointer] != 1 {
+                pointer += 1;
+            }
+            leaf = pointer;
+        }
+    }
+    edges.push((leaf, n - 1));
+    edges
+}
+
+/// Orients the edges of a tree from node 0 to the leaves, in breadth-first order.
+fn rooted_edges(
+    n: usize,
+    edges: &[(NodeIndexType, NodeIndexType)],
+) -> Vec<(NodeIndexType, NodeIndexType)> {
+    let mut neighbors: Vec<Vec<NodeIndexType>> = vec![vec![]; n];
+    edges.iter().for_each(|(i, j)| {
+        neighbors[*i].push(*j);
+        neighbors[*j].push(*i);
+    });
+    let mut visited = vec![false; n];
+    visited[0] = true;
+    let mut queue = VecDeque::from([0]);
+    let mut rooted = Vec::with_capacity(edges.len());
+    while let Some(i) = queue.pop_front() {
+        neighbors[i].iter().for_each(|j| {
+            if !visited[*j] {
+                visited[*j] = true;
+                rooted.push((i, *j));
+                queue.push_back(*j);
+            }
+        })
+    }
+    rooted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::{algo::is_cyclic_undirected, Directed, Undirected};
+    use rand::SeedableRng;
+    use rand_pcg::Pcg32;
+
+    #[test]
+    fn test_decode_pruefer_sequence() {
+        let mut edges = decode_pruefer_sequence(&[3, 3, 3, 4]);
+        edges.sort_unstable();
+        assert_eq!(vec![(0, 3), (1, 3), (2, 3), (3, 4), (4, 5)], edges);
+    }
+
+    #[test]
+    fn test_uniform_tree_of_two() {
+        let g: Graph<Directed> = UniformTreeGeneratorFactory
+            .try_with_params(vec![ParameterValue::PositiveInteger(2)])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(
+            vec![(0, 1)],
+            g.iter_edges()
+                .collect::<Vec<(NodeIndexType, NodeIndexType)>>()
+        );
+    }
+
+    #[test]
+    fn test_uniform_tree() {
+        let g: Graph<Undirected> = UniformTreeGeneratorFactory
+            .try_with_params(vec![ParameterValue::PositiveInteger(100)])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(100, g.n_nodes());
+        assert_eq!(99, g.n_edges());
+        assert!(!is_cyclic_undirected(g.petgraph()));
+    }
+
+    #[test]
+    fn test_uniform_tree_rooted() {
+        let g: Graph<Directed> = UniformTreeGeneratorFactory
+            .try_with_params(vec![ParameterValue::PositiveInteger(100)])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        let mut in_degrees = vec![0; 100];
+        g.iter_edges().for_each(|(_, j)| in_degrees[j] += 1);
+        assert_eq!(0, in_degrees[0]);
+        assert!(in_degrees[1..].iter().all(|d| *d == 1));
+    }
+
+    #[test]
+    fn test_recursive_tree() {
+        let g: Graph<Directed> = RecursiveTreeGeneratorFactory
+            .try_with_params(vec![ParameterValue::PositiveInteger(100)])
+            .unwrap()(&mut Pcg32::seed_from_u64(0));
+        assert_eq!(100, g.n_nodes());
+        assert_eq!(99, g.n_edges());
+        g.iter_edges().enumerate().for_each(|(k, (i, j))| {
+            assert_eq!(k + 1, j);
+            assert!(i < j);
+        });
+    }
+}
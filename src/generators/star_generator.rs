@@ -0,0 +1,88 @@
+use super::{BoxedGenerator, GeneratorFactory};
+use crate::{Graph, NamedParam, ParameterType, ParameterValue};
+use anyhow::Result;
+use petgraph::EdgeType;
+use rand::Rng;
+
+/// A factory used to build generators for [star graphs](https://en.wikipedia.org/wiki/Star_(graph_theory)).
+///
+/// The node with index 0 is the center of the star, and it is linked to all the other nodes.
+///
+/// In directed graphs generated by this objects, edges go from the center to the other nodes.
+///
+/// Such factories can be created by passing `star/n` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str),
+/// where `n` is the number of nodes (including the center) and must be at least 0.
+#[derive(Default)]
+pub struct StarGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for StarGeneratorFactory
+where
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "star"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator producing a star graph.",
+            "The first parameter gives the number of nodes, including the center.",
+            "In directed graphs, edges go from the center to the other nodes.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![ParameterType::PositiveInteger]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let n = parameter_values[0].unwrap_usize();
+        Ok(Box::new(move |_| {
+            let mut g = Graph::with_capacity(n, n.saturating_sub(1));
+            (0..n).for_each(|_| g.new_node());
+            (1..n).for_each(|i| g.new_edge(0, i));
+            g
+        }))
+    }
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for StarGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NodeIndexType;
+    use petgraph::Directed;
+
+    #[test]
+    fn test_star_of_zero() {
+        let mut rng = rand::thread_rng();
+        let g: Graph<Directed> = StarGeneratorFactory
+            .try_with_params(vec![ParameterValue::PositiveInteger(0)])
+            .unwrap()(&mut rng);
+        assert_eq!(0, g.n_nodes());
+        assert_eq!(0, g.n_edges());
+    }
+
+    #[test]
+    fn test_star() {
+        let mut rng = rand::thread_rng();
+        let g: Graph<Directed> = StarGeneratorFactory
+            .try_with_params(vec![ParameterValue::PositiveInteger(4)])
+            .unwrap()(&mut rng);
+        assert_eq!(4, g.n_nodes());
+        assert_eq!(
+            vec![(0, 1), (0, 2), (0, 3)],
+            g.iter_edges()
+                .collect::<Vec<(NodeIndexType, NodeIndexType)>>()
+        );
+    }
+}
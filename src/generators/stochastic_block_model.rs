@@ -0,0 +1,181 @@
+use super::{BoxedGenerator, GeneratorFactory, COMMUNITY_ATTRIBUTE};
+use crate::{Graph, NamedParam, NodeAttributeValue, ParameterType, ParameterValue};
+use anyhow::{anyhow, Context, Result};
+use petgraph::EdgeType;
+use rand::{distributions::Uniform, prelude::Distribution, Rng};
+
+/// A factory used to build generators for graphs following the [stochastic block model](https://en.wikipedia.org/wiki/Stochastic_block_model).
+///
+/// Nodes are split into consecutive blocks (the first `s1` nodes belong to the first block, the next `s2` to the second one, and so on).
+/// Each pair of nodes belonging to the same block is linked with probability `p_in`, while pairs of nodes from different blocks are linked with probability `p_out`.
+///
+/// In directed graphs generated by this objects, for each pair of nodes, both edges are considered for addition (0, 1 or 2 edges can be generated).
+///
+/// Such factories can be created by passing `sbm/s1:s2:...:sk,p_in,p_out` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str) where
+///   - `s1:s2:...:sk` is the list of the block sizes, separated by colons;
+///   - `p_in` is the probability each edge appears between two nodes of the same block;
+///   - `p_out` is the probability each edge appears between two nodes of different blocks.
+///
+/// The block of each node is stored as its `community` attribute.
+///
+/// Block sizes must be higher than zero, and both probabilities must be floating point numbers between 0 and 1.
+#[derive(Default)]
+pub struct StochasticBlockModelGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for StochasticBlockModelGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "sbm"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator following the stochastic block model.",
+            "First parameter gives the sizes of the blocks, separated by colons (eg. 10:20:30).",
+            "The second one gives the probability each edge appears inside a block, while the third one gives this probability for edges between blocks."
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![
+            ParameterType::PositiveIntegerList,
+            ParameterType::Probability,
+            ParameterType::Probability,
+        ]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let context = "while building a stochastic block model generator";
+        let block_sizes = parameter_values[0].unwrap_usize_list().to_vec();
+        let p_in = parameter_values[1].unwrap_f64();
+        let p_out = parameter_values[2].unwrap_f64();
+        if block_sizes.contains(&0) {
+            return Err(anyhow!(
+                r#"block sizes given by the first parameter must be higher than 0"#
+            ))
+            .context(context);
+        }
+        let blocks = block_sizes
+            .iter()
+            .enumerate()
+            .flat_map(|(i, s)| std::iter::repeat_n(i, *s))
+            .collect::<Vec<usize>>();
+        Ok(Box::new(move |r| build_graph(&blocks, p_in, p_out, r)))
+    }
+}
+
+fn build_graph<Ty, R>(blocks: &[usize], p_in: f64, p_out: f64, r: &mut R) -> Graph<Ty>
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    let n = blocks.len();
+    let mut g = Graph::with_capacity(n, 0);
+    (0..n).for_each(|i| {
+        g.new_node();
+        g.set_node_attribute(
+            i,
+            COMMUNITY_ATTRIBUTE,
+            NodeAttributeValue::PositiveInteger(blocks[i]),
+        );
+    });
+    let proba_uniform = Uniform::new(0., 1.);
+    let mut try_edge = |g: &mut Graph<Ty>, i: usize, j: usize| {
+        let p = if blocks[i] == blocks[j] { p_in } else { p_out };
+        if proba_uniform.sample(r) < p {
+            g.new_edge(i, j);
+        }
+    };
+    (0..n).for_each(|i| {
+        (i + 1..n).for_each(|j| {
+            try_edge(&mut g, i, j);
+            if Ty::is_directed() {
+                try_edge(&mut g, j, i);
+            }
+        })
+    });
+    g
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for StochasticBlockModelGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NodeIndexType;
+    use petgraph::{Directed, Undirected};
+    use rand::rngs::ThreadRng;
+
+    #[test]
+    fn test_empty_block() {
+        assert!((StochasticBlockModelGeneratorFactory.try_with_params(vec![
+            ParameterValue::PositiveIntegerList(vec![2, 0]),
+            ParameterValue::Probability(0.5),
+            ParameterValue::Probability(0.5)
+        ]) as Result<BoxedGenerator<Directed, ThreadRng>>)
+            .is_err())
+    }
+
+    #[test]
+    fn test_disjoint_cliques_directed() {
+        let mut rng = rand::thread_rng();
+        let g: Graph<Directed> = StochasticBlockModelGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveIntegerList(vec![2, 3]),
+                ParameterValue::Probability(1.0),
+                ParameterValue::Probability(0.0),
+            ])
+            .unwrap()(&mut rng);
+        assert_eq!(5, g.n_nodes());
+        assert_eq!(
+            Some(&NodeAttributeValue::PositiveInteger(1)),
+            g.node_attributes(2).get(COMMUNITY_ATTRIBUTE)
+        );
+        let mut edges = g
+            .iter_edges()
+            .collect::<Vec<(NodeIndexType, NodeIndexType)>>();
+        edges.sort_unstable();
+        assert_eq!(
+            vec![
+                (0, 1),
+                (1, 0),
+                (2, 3),
+                (2, 4),
+                (3, 2),
+                (3, 4),
+                (4, 2),
+                (4, 3)
+            ],
+            edges
+        );
+    }
+
+    #[test]
+    fn test_complete_bipartite_undirected() {
+        let mut rng = rand::thread_rng();
+        let g: Graph<Undirected> = StochasticBlockModelGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveIntegerList(vec![1, 2]),
+                ParameterValue::Probability(0.0),
+                ParameterValue::Probability(1.0),
+            ])
+            .unwrap()(&mut rng);
+        assert_eq!(3, g.n_nodes());
+        let mut edges = g
+            .iter_edges()
+            .collect::<Vec<(NodeIndexType, NodeIndexType)>>();
+        edges.sort_unstable();
+        assert_eq!(vec![(0, 1), (0, 2)], edges);
+    }
+}
@@ -1,6 +1,6 @@
 use super::{BoxedGenerator, GeneratorFactory};
 use crate::{Graph, NamedParam, ParameterType, ParameterValue};
-use anyhow::Result;
+use anyhow::{anyhow, Context, Result};
 use petgraph::EdgeType;
 use rand::Rng;
 
@@ -38,27 +38,7 @@ where
         parameter_values: Vec<ParameterValue>,
     ) -> Result<BoxedGenerator<Ty, R>> {
         let n = parameter_values[0].unwrap_usize();
-        Ok(Box::new(move |_| match n {
-            0 => Graph::default(),
-            1 => {
-                let mut g = Graph::with_capacity(1, 0);
-                g.new_node();
-                g
-            }
-            _ => {
-                let mut g = Graph::with_capacity(n, 0);
-                (0..n).for_each(|i| {
-                    if 2 * i + 1 < n {
-                        g.new_edge(i, 2 * i + 1);
-                    }
-
-                    if 2 * i + 2 < n {
-                        g.new_edge(i, 2 * i + 2);
-                    }
-                });
-                g
-            }
-        }))
+        Ok(Box::new(move |_| build_kary_tree(n, 2)))
     }
 }
 
@@ -69,11 +49,78 @@ where
 {
 }
 
+/// A factory used to build generators for complete k-ary trees.
+///
+/// Nodes are numbered in breadth-first order: the children of node `i` are the nodes `k*i+1` to `k*i+k`.
+/// All the levels of the tree are full, except possibly the last one.
+///
+/// In directed graphs generated by this objects, all edges follow paths from the root to the leaves.
+///
+/// Such factories can be created by passing `kary_tree/n,k` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str) where
+///   - `n` is the number of nodes;
+///   - `k` is the arity of the tree, and must be higher than zero.
+#[derive(Default)]
+pub struct KaryTreeGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for KaryTreeGeneratorFactory
+where
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "kary_tree"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator producing a complete k-ary tree.",
+            "The first parameter gives the number of nodes, and the second one the arity of the tree.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![
+            ParameterType::PositiveInteger,
+            ParameterType::PositiveInteger,
+        ]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let n = parameter_values[0].unwrap_usize();
+        let k = parameter_values[1].unwrap_usize();
+        if k == 0 {
+            return Err(anyhow!(r#"second parameter ("k") must be higher than 0"#))
+                .context("while building a k-ary tree generator");
+        }
+        Ok(Box::new(move |_| build_kary_tree(n, k)))
+    }
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for KaryTreeGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+fn build_kary_tree<Ty>(n: usize, k: usize) -> Graph<Ty>
+where
+    Ty: EdgeType,
+{
+    let mut g = Graph::with_capacity(n, n.saturating_sub(1));
+    (0..n).for_each(|_| g.new_node());
+    (1..n).for_each(|i| g.new_edge((i - 1) / k, i));
+    g
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::NodeIndexType;
     use petgraph::Directed;
+    use rand::rngs::ThreadRng;
 
     #[test]
     fn test_tree_of_zero() {
@@ -108,4 +155,30 @@ mod tests {
                 .collect::<Vec<(NodeIndexType, NodeIndexType)>>()
         );
     }
+
+    #[test]
+    fn test_kary_tree_zero_arity() {
+        assert!((KaryTreeGeneratorFactory.try_with_params(vec![
+            ParameterValue::PositiveInteger(4),
+            ParameterValue::PositiveInteger(0)
+        ]) as Result<BoxedGenerator<Directed, ThreadRng>>)
+            .is_err())
+    }
+
+    #[test]
+    fn test_kary_tree() {
+        let mut rng = rand::thread_rng();
+        let g: Graph<Directed> = KaryTreeGeneratorFactory
+            .try_with_params(vec![
+                ParameterValue::PositiveInteger(6),
+                ParameterValue::PositiveInteger(3),
+            ])
+            .unwrap()(&mut rng);
+        assert_eq!(6, g.n_nodes());
+        assert_eq!(
+            vec![(0, 1), (0, 2), (0, 3), (1, 4), (1, 5)],
+            g.iter_edges()
+                .collect::<Vec<(NodeIndexType, NodeIndexType)>>()
+        );
+    }
 }
@@ -0,0 +1,90 @@
+use super::{cycle_generator::add_cycle, BoxedGenerator, GeneratorFactory};
+use crate::{Graph, NamedParam, ParameterType, ParameterValue};
+use anyhow::Result;
+use petgraph::EdgeType;
+use rand::Rng;
+
+/// A factory used to build generators for [wheel graphs](https://en.wikipedia.org/wiki/Wheel_graph).
+///
+/// The node with index 0 is the hub of the wheel, and it is linked to all the other nodes, which form a cycle (the rim).
+/// If the rim has less than 3 nodes, it is a path rather than a cycle.
+///
+/// In directed graphs generated by this objects, edges go from the hub to the rim, and along the rim from nodes to the ones with the same index plus one (the last node being linked to the first node of the rim).
+///
+/// Such factories can be created by passing `wheel/n` to [`generators::generator_factory_from_str`](crate::generators#generator_factory_from_str),
+/// where `n` is the number of nodes (including the hub) and must be at least 0.
+#[derive(Default)]
+pub struct WheelGeneratorFactory;
+
+impl<Ty, R> NamedParam<BoxedGenerator<Ty, R>> for WheelGeneratorFactory
+where
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "wheel"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "A generator producing a wheel graph.",
+            "The first parameter gives the number of nodes, including the hub.",
+            "In directed graphs, edges go from the hub to the rim, and follow the rim in one direction.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![ParameterType::PositiveInteger]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedGenerator<Ty, R>> {
+        let n = parameter_values[0].unwrap_usize();
+        Ok(Box::new(move |_| {
+            let mut g = Graph::with_capacity(n, 2 * n.saturating_sub(1));
+            (0..n).for_each(|_| g.new_node());
+            (1..n).for_each(|i| g.new_edge(0, i));
+            add_cycle(&mut g, &(1..n).collect::<Vec<usize>>());
+            g
+        }))
+    }
+}
+
+impl<Ty, R> GeneratorFactory<Ty, R> for WheelGeneratorFactory
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NodeIndexType;
+    use petgraph::Directed;
+
+    #[test]
+    fn test_wheel_of_one() {
+        let mut rng = rand::thread_rng();
+        let g: Graph<Directed> = WheelGeneratorFactory
+            .try_with_params(vec![ParameterValue::PositiveInteger(1)])
+            .unwrap()(&mut rng);
+        assert_eq!(1, g.n_nodes());
+        assert_eq!(0, g.n_edges());
+    }
+
+    #[test]
+    fn test_wheel() {
+        let mut rng = rand::thread_rng();
+        let g: Graph<Directed> = WheelGeneratorFactory
+            .try_with_params(vec![ParameterValue::PositiveInteger(4)])
+            .unwrap()(&mut rng);
+        assert_eq!(4, g.n_nodes());
+        assert_eq!(
+            vec![(0, 1), (0, 2), (0, 3), (1, 2), (2, 3), (3, 1)],
+            g.iter_edges()
+                .collect::<Vec<(NodeIndexType, NodeIndexType)>>()
+        );
+    }
+}
@@ -8,6 +8,8 @@ pub use crate::core::InnerOuterGenerationStep;
 pub use crate::core::InnerOuterGenerator;
 pub use crate::core::InterGraphEdge;
 pub use crate::core::NamedParam;
+pub use crate::core::NodeAttributeValue;
+pub use crate::core::NodeAttributes;
 pub use crate::core::NodeIndexType;
 pub use crate::core::ParameterType;
 pub use crate::core::ParameterValue;
@@ -16,10 +18,48 @@ pub mod display;
 
 pub mod generators;
 pub use generators::BarabasiAlbertGeneratorFactory;
+pub use generators::BipartiteConfigurationModelGeneratorFactory;
+pub use generators::BollobasBorgsChayesRiordanGeneratorFactory;
+pub use generators::ChungLuFileGeneratorFactory;
+pub use generators::ChungLuGeneratorFactory;
+pub use generators::CompleteBipartiteGeneratorFactory;
+pub use generators::CompleteGeneratorFactory;
+pub use generators::ConfigurationModelFileGeneratorFactory;
+pub use generators::ConfigurationModelGeneratorFactory;
+pub use generators::CopyingModelGeneratorFactory;
+pub use generators::CycleGeneratorFactory;
+pub use generators::DuplicationDivergenceGeneratorFactory;
 pub use generators::ErdosRenyiGeneratorFactory;
+pub use generators::ErdosRenyiGnmGeneratorFactory;
+pub use generators::FileDirectoryGeneratorFactory;
+pub use generators::FileGeneratorFactory;
+pub use generators::ForestFireGeneratorFactory;
+pub use generators::GridGeneratorFactory;
+pub use generators::HolmeKimGeneratorFactory;
+pub use generators::HyperbolicGeneratorFactory;
+pub use generators::HypercubeGeneratorFactory;
+pub use generators::KaryTreeGeneratorFactory;
+pub use generators::KleinbergGeneratorFactory;
+pub use generators::LancichinettiFortunatoRadicchiGeneratorFactory;
 pub use generators::PathGeneratorFactory;
+pub use generators::RMatGeneratorFactory;
+pub use generators::RandomBipartiteGeneratorFactory;
+pub use generators::RandomDagGeneratorFactory;
+pub use generators::RandomDagOutDegreeGeneratorFactory;
+pub use generators::RandomGeometricGeneratorFactory;
+pub use generators::RandomRegularGeneratorFactory;
+pub use generators::RandomTournamentGeneratorFactory;
+pub use generators::RecursiveTreeGeneratorFactory;
+pub use generators::StarGeneratorFactory;
+pub use generators::StochasticBlockModelGeneratorFactory;
+pub use generators::StochasticKroneckerGeneratorFactory;
+pub use generators::TorusGeneratorFactory;
 pub use generators::TreeGeneratorFactory;
+pub use generators::UniformTreeGeneratorFactory;
 pub use generators::WattsStrogatzGeneratorFactory;
+pub use generators::WheelGeneratorFactory;
 
 pub mod linkers;
 pub use linkers::{BidirectionalFirstToFirstLinker, FirstToFirstLinker};
+
+pub mod parsers;
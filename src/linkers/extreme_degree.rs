@@ -0,0 +1,346 @@
+use super::{BoxedLinker, DegreeKind, InnerGraphCache, Linker};
+use crate::{core::InnerGraph, InterGraphEdge, NamedParam, ParameterType, ParameterValue};
+use anyhow::Result;
+use petgraph::{Directed, EdgeType};
+use rand::Rng;
+
+/// The extremum of the degree the linked nodes must reach.
+#[derive(Clone, Copy)]
+enum Extremum {
+    /// The nodes with the lowest degree are linked
+    Min,
+    /// The nodes with the highest degree are linked
+    Max,
+}
+
+macro_rules! extreme_degree_linkers {
+    (
+        $linker:ident,
+        $bi_linker:ident,
+        $name:expr,
+        $degree_kind:expr,
+        $extremum:expr,
+        $target:expr
+    ) => {
+        #[doc = concat!("A linker that connects graph by targeting their nodes with the ", $target, ".")]
+        ///
+        /// The nodes of each inner graph are computed once, and then cached using the index of the inner graph.
+        /// For undirected graphs, the counts of incoming and outgoing edges are the number of incident edges.
+        ///
+        #[doc = concat!("Such linker can be created by passing `", $name, "` to [`linkers::linker_from_str`](crate::linkers#linker_from_str).")]
+        #[derive(Default)]
+        pub struct $linker;
+
+        impl<Ty, R> NamedParam<BoxedLinker<Ty, R>> for $linker
+        where
+            R: Rng,
+            Ty: EdgeType,
+        {
+            fn name(&self) -> &'static str {
+                $name
+            }
+
+            fn description(&self) -> Vec<&'static str> {
+                vec![concat!(
+                    "Links the nodes of the first graph with the ",
+                    $target,
+                    " to the nodes of the second graph with the same property."
+                )]
+            }
+
+            fn expected_parameter_types(&self) -> Vec<ParameterType> {
+                vec![]
+            }
+
+            fn try_with_params(
+                &self,
+                _parameter_values: Vec<ParameterValue>,
+            ) -> Result<BoxedLinker<Ty, R>> {
+                try_with_params($degree_kind, $extremum, false)
+            }
+        }
+
+        impl<Ty, R> Linker<Ty, R> for $linker
+        where
+            R: Rng,
+            Ty: EdgeType,
+        {
+        }
+
+        #[doc = concat!("A bidirectional linker that connects graph by targeting their nodes with the ", $target, ".")]
+        ///
+        /// The nodes of each inner graph are computed once, and then cached using the index of the inner graph.
+        ///
+        #[doc = concat!("Such linker can be created by passing `", $name, "_bi` to [`linkers::linker_from_str`](crate::linkers#linker_from_str).")]
+        #[derive(Default)]
+        pub struct $bi_linker;
+
+        impl<R> NamedParam<BoxedLinker<Directed, R>> for $bi_linker
+        where
+            R: Rng,
+        {
+            fn name(&self) -> &'static str {
+                concat!($name, "_bi")
+            }
+
+            fn description(&self) -> Vec<&'static str> {
+                vec![concat!(
+                    "Links the nodes of the first graph with the ",
+                    $target,
+                    " to the nodes of the second graph with the same property, and vice-versa."
+                )]
+            }
+
+            fn expected_parameter_types(&self) -> Vec<ParameterType> {
+                vec![]
+            }
+
+            fn try_with_params(
+                &self,
+                _parameter_values: Vec<ParameterValue>,
+            ) -> Result<BoxedLinker<Directed, R>> {
+                try_with_params($degree_kind, $extremum, true)
+            }
+        }
+
+        impl<R> Linker<Directed, R> for $bi_linker where R: Rng {}
+    };
+}
+
+extreme_degree_linkers!(
+    MinIncomingLinker,
+    BidirectionalMinIncomingLinker,
+    "min_incoming",
+    DegreeKind::In,
+    Extremum::Min,
+    "lowest count of incoming edges"
+);
+
+extreme_degree_linkers!(
+    MaxIncomingLinker,
+    BidirectionalMaxIncomingLinker,
+    "max_incoming",
+    DegreeKind::In,
+    Extremum::Max,
+    "highest count of incoming edges"
+);
+
+extreme_degree_linkers!(
+    MinOutgoingLinker,
+    BidirectionalMinOutgoingLinker,
+    "min_outgoing",
+    DegreeKind::Out,
+    Extremum::Min,
+    "lowest count of outgoing edges"
+);
+
+extreme_degree_linkers!(
+    MaxOutgoingLinker,
+    BidirectionalMaxOutgoingLinker,
+    "max_outgoing",
+    DegreeKind::Out,
+    Extremum::Max,
+    "highest count of outgoing edges"
+);
+
+extreme_degree_linkers!(
+    MaxDegreeLinker,
+    BidirectionalMaxDegreeLinker,
+    "max_degree",
+    DegreeKind::Total,
+    Extremum::Max,
+    "highest count of incident edges"
+);
+
+fn try_with_params<Ty, R>(
+    degree_kind: DegreeKind,
+    extremum: Extremum,
+    bidirectional: bool,
+) -> Result<BoxedLinker<Ty, R>>
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    let cache = InnerGraphCache::default();
+    Ok(Box::new(move |g1, g2, _| {
+        let extreme_nodes_1 = compute_extreme_nodes(&g1, degree_kind, extremum, &cache);
+        let extreme_nodes_2 = compute_extreme_nodes(&g2, degree_kind, extremum, &cache);
+        let mut links = Vec::new();
+        extreme_nodes_1.iter().for_each(|n1| {
+            extreme_nodes_2.iter().for_each(|n2| {
+                links.push(InterGraphEdge::FirstToSecond(*n1, *n2));
+                if bidirectional {
+                    links.push(InterGraphEdge::SecondToFirst(*n2, *n1));
+                }
+            });
+        });
+        links
+    }))
+}
+
+fn compute_extreme_nodes<Ty>(
+    g: &InnerGraph<Ty>,
+    degree_kind: DegreeKind,
+    extremum: Extremum,
+    cache: &InnerGraphCache<Vec<usize>>,
+) -> Vec<usize>
+where
+    Ty: EdgeType,
+{
+    cache.get_or_compute(g, || {
+        let degrees = super::degrees(g.graph(), degree_kind);
+        let extreme_degree = match extremum {
+            Extremum::Min => degrees.iter().min(),
+            Extremum::Max => degrees.iter().max(),
+        }
+        .copied()
+        .unwrap_or_default();
+        degrees
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, d)| if d == extreme_degree { Some(i) } else { None })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Graph;
+    use petgraph::{Directed, Undirected};
+
+    #[test]
+    fn test_min_incoming_ok() {
+        let mut g0: Graph<Directed> = Graph::default();
+        g0.new_node();
+        g0.new_node();
+        let mut g1 = Graph::default();
+        g1.new_edge(0, 1);
+        let linker = MinIncomingLinker.try_with_params(vec![]).unwrap();
+        assert_eq!(
+            vec![
+                InterGraphEdge::FirstToSecond(0, 0),
+                InterGraphEdge::FirstToSecond(1, 0)
+            ],
+            linker((0, &g0).into(), (1, &g1).into(), &mut rand::thread_rng())
+        );
+    }
+
+    #[test]
+    fn test_min_incoming_bi_ok() {
+        let mut g0: Graph<Directed> = Graph::default();
+        g0.new_node();
+        g0.new_node();
+        let mut g1 = Graph::default();
+        g1.new_edge(0, 1);
+        let linker = BidirectionalMinIncomingLinker
+            .try_with_params(vec![])
+            .unwrap();
+        assert_eq!(
+            vec![
+                InterGraphEdge::FirstToSecond(0, 0),
+                InterGraphEdge::SecondToFirst(0, 0),
+                InterGraphEdge::FirstToSecond(1, 0),
+                InterGraphEdge::SecondToFirst(0, 1),
+            ],
+            linker((0, &g0).into(), (1, &g1).into(), &mut rand::thread_rng())
+        );
+    }
+
+    #[test]
+    fn test_min_incoming_undirected_ok() {
+        let mut g0: Graph<Undirected> = Graph::default();
+        g0.new_edge(0, 1);
+        g0.new_edge(0, 2);
+        let mut g1 = Graph::default();
+        g1.new_edge(0, 1);
+        g1.new_edge(1, 2);
+        let linker = MinIncomingLinker.try_with_params(vec![]).unwrap();
+        assert_eq!(
+            vec![
+                InterGraphEdge::FirstToSecond(1, 0),
+                InterGraphEdge::FirstToSecond(1, 2),
+                InterGraphEdge::FirstToSecond(2, 0),
+                InterGraphEdge::FirstToSecond(2, 2),
+            ],
+            linker((0, &g0).into(), (1, &g1).into(), &mut rand::thread_rng())
+        );
+    }
+
+    fn star_and_path() -> (Graph<Directed>, Graph<Directed>) {
+        let mut star = Graph::default();
+        (1..4).for_each(|i| star.new_edge(0, i));
+        let mut path = Graph::default();
+        (0..3).for_each(|i| path.new_edge(i, i + 1));
+        (star, path)
+    }
+
+    #[test]
+    fn test_max_incoming_ok() {
+        let (g0, g1) = star_and_path();
+        let linker = MaxIncomingLinker.try_with_params(vec![]).unwrap();
+        assert_eq!(
+            vec![
+                InterGraphEdge::FirstToSecond(1, 1),
+                InterGraphEdge::FirstToSecond(1, 2),
+                InterGraphEdge::FirstToSecond(1, 3),
+                InterGraphEdge::FirstToSecond(2, 1),
+                InterGraphEdge::FirstToSecond(2, 2),
+                InterGraphEdge::FirstToSecond(2, 3),
+                InterGraphEdge::FirstToSecond(3, 1),
+                InterGraphEdge::FirstToSecond(3, 2),
+                InterGraphEdge::FirstToSecond(3, 3),
+            ],
+            linker((0, &g0).into(), (1, &g1).into(), &mut rand::thread_rng())
+        );
+    }
+
+    #[test]
+    fn test_min_outgoing_ok() {
+        let (g0, g1) = star_and_path();
+        let linker = MinOutgoingLinker.try_with_params(vec![]).unwrap();
+        assert_eq!(
+            vec![
+                InterGraphEdge::FirstToSecond(1, 3),
+                InterGraphEdge::FirstToSecond(2, 3),
+                InterGraphEdge::FirstToSecond(3, 3),
+            ],
+            linker((0, &g0).into(), (1, &g1).into(), &mut rand::thread_rng())
+        );
+    }
+
+    #[test]
+    fn test_max_outgoing_bi_ok() {
+        let (g0, g1) = star_and_path();
+        let linker = BidirectionalMaxOutgoingLinker
+            .try_with_params(vec![])
+            .unwrap();
+        assert_eq!(
+            vec![
+                InterGraphEdge::FirstToSecond(0, 0),
+                InterGraphEdge::SecondToFirst(0, 0),
+                InterGraphEdge::FirstToSecond(0, 1),
+                InterGraphEdge::SecondToFirst(1, 0),
+                InterGraphEdge::FirstToSecond(0, 2),
+                InterGraphEdge::SecondToFirst(2, 0),
+            ],
+            linker((0, &g0).into(), (1, &g1).into(), &mut rand::thread_rng())
+        );
+    }
+
+    #[test]
+    fn test_max_degree_undirected_ok() {
+        let mut g0: Graph<Undirected> = Graph::default();
+        (1..4).for_each(|i| g0.new_edge(i, 0));
+        let mut g1 = Graph::default();
+        (0..3).for_each(|i| g1.new_edge(i, i + 1));
+        let linker = MaxDegreeLinker.try_with_params(vec![]).unwrap();
+        assert_eq!(
+            vec![
+                InterGraphEdge::FirstToSecond(0, 1),
+                InterGraphEdge::FirstToSecond(0, 2),
+            ],
+            linker((0, &g0).into(), (1, &g1).into(), &mut rand::thread_rng())
+        );
+    }
+}
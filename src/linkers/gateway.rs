@@ -0,0 +1,255 @@
+use super::{BoxedLinker, InnerGraphCache, Linker};
+use crate::{core::InnerGraph, InterGraphEdge, NamedParam, ParameterType, ParameterValue};
+use anyhow::Result;
+use petgraph::{Directed, EdgeType};
+use rand::{seq::index, Rng, SeedableRng};
+use rand_pcg::Pcg32;
+
+/// A linker that connects graphs through a fixed number of gateway nodes per inner graph.
+///
+/// Each inner graph designates `g` gateway nodes (or all its nodes if it has fewer than `g` of them), chosen uniformly at random once,
+/// and then cached using the index of the inner graph.
+/// When two graphs are linked, each gateway of the first graph is connected to each gateway of the second one,
+/// so all the links of an inner graph go through its gateways.
+///
+/// Since inner graphs are linked in parallel, the gateways are not drawn with the PRNG given to the linker, which depends on the pair of graphs being linked.
+/// Instead, they are drawn with a PRNG seeded by the seed associated with the inner graph, which is drawn once from the main PRNG.
+///
+/// Such linker can be created by passing `gateway/g` to [`linkers::linker_from_str`](crate::linkers#linker_from_str),
+/// where `g` is the number of gateways per inner graph.
+#[derive(Default)]
+pub struct GatewayLinker;
+
+impl<Ty, R> NamedParam<BoxedLinker<Ty, R>> for GatewayLinker
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "gateway"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "Links the gateway nodes of the first graph to the gateway nodes of the second graph.",
+            "The gateways of each graph are chosen at random once; their number is given by the first parameter.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![ParameterType::PositiveInteger]
+    }
+
+    fn try_with_params(&self, parameter_values: Vec<ParameterValue>) -> Result<BoxedLinker<Ty, R>> {
+        try_with_params(parameter_values, false)
+    }
+}
+
+impl<Ty, R> Linker<Ty, R> for GatewayLinker
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+/// A bidirectional linker that connects graphs through a fixed number of gateway nodes per inner graph.
+///
+/// The gateways are chosen as for the [`GatewayLinker`]; each gateway of the first graph is connected to each gateway of the second one, and vice-versa.
+///
+/// Such linker can be created by passing `gateway_bi/g` to [`linkers::linker_from_str`](crate::linkers#linker_from_str),
+/// where `g` is the number of gateways per inner graph.
+#[derive(Default)]
+pub struct BidirectionalGatewayLinker;
+
+impl<R> NamedParam<BoxedLinker<Directed, R>> for BidirectionalGatewayLinker
+where
+    R: Rng,
+{
+    fn name(&self) -> &'static str {
+        "gateway_bi"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "Links the gateway nodes of the first graph to the gateway nodes of the second graph, and vice-versa.",
+            "The gateways of each graph are chosen at random once; their number is given by the first parameter.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![ParameterType::PositiveInteger]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedLinker<Directed, R>> {
+        try_with_params(parameter_values, true)
+    }
+}
+
+impl<R> Linker<Directed, R> for BidirectionalGatewayLinker where R: Rng {}
+
+fn try_with_params<Ty, R>(
+    parameter_values: Vec<ParameterValue>,
+    bidirectional: bool,
+) -> Result<BoxedLinker<Ty, R>>
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    let n_gateways = parameter_values[0].unwrap_usize();
+    let cache = InnerGraphCache::default();
+    Ok(Box::new(move |g1, g2, _| {
+        let gateways_1 = compute_gateways(&g1, n_gateways, &cache);
+        let gateways_2 = compute_gateways(&g2, n_gateways, &cache);
+        let mut links = Vec::with_capacity(
+            gateways_1.len() * gateways_2.len() * if bidirectional { 2 } else { 1 },
+        );
+        gateways_1.iter().for_each(|n1| {
+            gateways_2.iter().for_each(|n2| {
+                links.push(InterGraphEdge::FirstToSecond(*n1, *n2));
+                if bidirectional {
+                    links.push(InterGraphEdge::SecondToFirst(*n2, *n1));
+                }
+            });
+        });
+        links
+    }))
+}
+
+fn compute_gateways<Ty>(
+    g: &InnerGraph<Ty>,
+    n_gateways: usize,
+    cache: &InnerGraphCache<Vec<usize>>,
+) -> Vec<usize>
+where
+    Ty: EdgeType,
+{
+    cache.get_or_compute(g, || {
+        let n_nodes = g.graph().n_nodes();
+        let mut rng = Pcg32::seed_from_u64(g.seed());
+        let mut v = index::sample(&mut rng, n_nodes, n_gateways.min(n_nodes)).into_vec();
+        v.sort_unstable();
+        v
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generators::{BoxedGenerator, PathGeneratorFactory},
+        Graph, InnerOuterGenerator, NodeIndexType,
+    };
+
+    fn path(n: usize) -> Graph<Directed> {
+        let mut g = Graph::default();
+        (1..n).for_each(|i| g.new_edge(i - 1, i));
+        g
+    }
+
+    fn first_endpoints(edges: &[InterGraphEdge]) -> Vec<usize> {
+        let mut endpoints = edges
+            .iter()
+            .filter_map(|e| match e {
+                InterGraphEdge::FirstToSecond(i, _) => Some(*i),
+                InterGraphEdge::SecondToFirst(_, _) => None,
+            })
+            .collect::<Vec<usize>>();
+        endpoints.sort_unstable();
+        endpoints.dedup();
+        endpoints
+    }
+
+    #[test]
+    fn test_gateway() {
+        let graphs = (0..3).map(|_| path(20)).collect::<Vec<Graph<Directed>>>();
+        let linker = GatewayLinker
+            .try_with_params(vec![ParameterValue::PositiveInteger(3)])
+            .unwrap();
+        let edges_01 = linker(
+            (0, &graphs[0]).into(),
+            (1, &graphs[1]).into(),
+            &mut rand::thread_rng(),
+        );
+        assert_eq!(9, edges_01.len());
+        let edges_02 = linker(
+            (0, &graphs[0]).into(),
+            (2, &graphs[2]).into(),
+            &mut rand::thread_rng(),
+        );
+        assert_eq!(3, first_endpoints(&edges_01).len());
+        assert_eq!(first_endpoints(&edges_01), first_endpoints(&edges_02));
+    }
+
+    #[test]
+    fn test_gateway_reproducible() {
+        let g0 = path(20);
+        let g1 = path(20);
+        let link = || {
+            let linker = GatewayLinker
+                .try_with_params(vec![ParameterValue::PositiveInteger(2)])
+                .unwrap();
+            linker((0, &g0).into(), (1, &g1).into(), &mut rand::thread_rng())
+        };
+        assert_eq!(link(), link());
+    }
+
+    #[test]
+    fn test_gateway_depends_on_seed() {
+        let edges = (1..4)
+            .map(|seed| {
+                let path_generator = || -> BoxedGenerator<Directed, Pcg32> {
+                    PathGeneratorFactory
+                        .try_with_params(vec![ParameterValue::PositiveInteger(20)])
+                        .unwrap()
+                };
+                let linker: BoxedLinker<Directed, Pcg32> = GatewayLinker
+                    .try_with_params(vec![ParameterValue::PositiveInteger(2)])
+                    .unwrap();
+                let g = InnerOuterGenerator::default().new_inner_outer(
+                    path_generator(),
+                    path_generator(),
+                    linker,
+                    &mut Pcg32::seed_from_u64(seed),
+                );
+                let mut edges = g
+                    .iter_edges()
+                    .collect::<Vec<(NodeIndexType, NodeIndexType)>>();
+                edges.sort_unstable();
+                edges
+            })
+            .collect::<Vec<Vec<(NodeIndexType, NodeIndexType)>>>();
+        assert!(edges[0] != edges[1] || edges[1] != edges[2]);
+    }
+
+    #[test]
+    fn test_gateway_small_graph() {
+        let g0 = path(2);
+        let g1 = path(20);
+        let linker = GatewayLinker
+            .try_with_params(vec![ParameterValue::PositiveInteger(5)])
+            .unwrap();
+        let edges = linker((0, &g0).into(), (1, &g1).into(), &mut rand::thread_rng());
+        assert_eq!(10, edges.len());
+        assert_eq!(vec![0, 1], first_endpoints(&edges));
+    }
+
+    #[test]
+    fn test_gateway_bi() {
+        let g0 = path(10);
+        let g1 = path(10);
+        let linker = BidirectionalGatewayLinker
+            .try_with_params(vec![ParameterValue::PositiveInteger(2)])
+            .unwrap();
+        let edges = linker((0, &g0).into(), (1, &g1).into(), &mut rand::thread_rng());
+        assert_eq!(8, edges.len());
+        edges.chunks(2).for_each(|pair| match pair {
+            [InterGraphEdge::FirstToSecond(i, j), InterGraphEdge::SecondToFirst(k, l)] => {
+                assert_eq!((i, j), (l, k))
+            }
+            _ => panic!(),
+        });
+    }
+}
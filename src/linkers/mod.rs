@@ -28,26 +28,43 @@
 //!
 //! In an undirected context, both values acts in a similar way (but only one should be added).
 //!
-//! Note that using [interior mutability](https://doc.rust-lang.org/book/ch15-05-interior-mutability.html), one can rely on the indices of the inner graph to eg. cache information in the structure.
-//! See the source code of the [`MinIncomingLinker`] to get an example of such caching methods.
+//! Note that using [interior mutability](https://doc.rust-lang.org/book/ch15-05-interior-mutability.html), one can rely on the indices of the inner graph to eg. cache information in the linker.
+//! Since these indices are only meaningful for a single generation process, such a cache must be created by [`try_with_params`](NamedParam::try_with_params) and owned by the returned closure;
+//! a cache held by the linker itself would be shared by all the generations, since the linkers are stored in static registries.
+//! This is why the [`MinIncomingLinker`] is a unit struct that creates a fresh cache each time a linker is built.
+//! See the source code of the [`MinIncomingLinker`] or the [`PreferentialLinker`] to get an example of such caching methods.
 
 mod first_to_first;
 pub use first_to_first::{BidirectionalFirstToFirstLinker, FirstToFirstLinker};
 
-mod min_incoming;
-pub use min_incoming::{BidirectionalMinIncomingLinker, MinIncomingLinker};
+mod extreme_degree;
+pub use extreme_degree::{BidirectionalMaxDegreeLinker, MaxDegreeLinker};
+pub use extreme_degree::{BidirectionalMaxIncomingLinker, MaxIncomingLinker};
+pub use extreme_degree::{BidirectionalMaxOutgoingLinker, MaxOutgoingLinker};
+pub use extreme_degree::{BidirectionalMinIncomingLinker, MinIncomingLinker};
+pub use extreme_degree::{BidirectionalMinOutgoingLinker, MinOutgoingLinker};
+
+mod gateway;
+pub use gateway::{BidirectionalGatewayLinker, GatewayLinker};
+
+mod preferential;
+pub use preferential::PreferentialLinker;
 
 mod random;
 pub use random::{BidirectionalRandomLinker, RandomLinker};
 
+mod random_count;
+pub use random_count::{BidirectionalRandomCountLinker, RandomCountLinker};
+
 use crate::{
     core::{named_param, InnerGraph},
-    InterGraphEdge, NamedParam,
+    Graph, InterGraphEdge, NamedParam,
 };
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
 use lazy_static::lazy_static;
 use petgraph::{Directed, EdgeType, Undirected};
 use rand_pcg::Pcg32;
+use std::{str::FromStr, sync::Mutex};
 
 /// A boxed function that take two graphs and return a set of edges that can be used to link them.
 /// The choice of the edges depends on the implementation of the linker.
@@ -68,21 +85,41 @@ where
 }
 
 lazy_static! {
-    pub(crate) static ref LINKERS_DIRECTED_PCG32: [Box<dyn Linker<Directed, Pcg32> + Sync>; 6] = [
-        Box::new(FirstToFirstLinker::default()),
-        Box::new(BidirectionalFirstToFirstLinker::default()),
-        Box::new(MinIncomingLinker::default()),
-        Box::new(BidirectionalMinIncomingLinker::default()),
-        Box::new(RandomLinker::default()),
-        Box::new(BidirectionalRandomLinker::default()),
+    pub(crate) static ref LINKERS_DIRECTED_PCG32: [Box<dyn Linker<Directed, Pcg32> + Sync>; 19] = [
+        Box::new(FirstToFirstLinker),
+        Box::new(BidirectionalFirstToFirstLinker),
+        Box::new(GatewayLinker),
+        Box::new(BidirectionalGatewayLinker),
+        Box::new(MaxDegreeLinker),
+        Box::new(BidirectionalMaxDegreeLinker),
+        Box::new(MaxIncomingLinker),
+        Box::new(BidirectionalMaxIncomingLinker),
+        Box::new(MaxOutgoingLinker),
+        Box::new(BidirectionalMaxOutgoingLinker),
+        Box::new(MinIncomingLinker),
+        Box::new(BidirectionalMinIncomingLinker),
+        Box::new(MinOutgoingLinker),
+        Box::new(BidirectionalMinOutgoingLinker),
+        Box::new(PreferentialLinker),
+        Box::new(RandomLinker),
+        Box::new(BidirectionalRandomLinker),
+        Box::new(RandomCountLinker),
+        Box::new(BidirectionalRandomCountLinker),
     ];
 }
 
 lazy_static! {
-    pub(crate) static ref LINKERS_UNDIRECTED_PCG32: [Box<dyn Linker<Undirected, Pcg32> + Sync>; 3] = [
-        Box::new(FirstToFirstLinker::default()),
-        Box::new(MinIncomingLinker::default()),
-        Box::new(RandomLinker::default()),
+    pub(crate) static ref LINKERS_UNDIRECTED_PCG32: [Box<dyn Linker<Undirected, Pcg32> + Sync>; 10] = [
+        Box::new(FirstToFirstLinker),
+        Box::new(GatewayLinker),
+        Box::new(MaxDegreeLinker),
+        Box::new(MaxIncomingLinker),
+        Box::new(MaxOutgoingLinker),
+        Box::new(MinIncomingLinker),
+        Box::new(MinOutgoingLinker),
+        Box::new(PreferentialLinker),
+        Box::new(RandomLinker),
+        Box::new(RandomCountLinker),
     ];
 }
 
@@ -138,6 +175,95 @@ pub fn undirected_linker_from_str(s: &str) -> Result<BoxedLinker<Undirected, Pcg
         .context("while building a linker from a string")
 }
 
+/// A cache storing data computed for inner graphs, using their indices.
+///
+/// Such a cache must be owned by the closure returned by a linker, and not by the linker itself,
+/// since the indices of inner graphs are only meaningful for a single generation process.
+struct InnerGraphCache<T>(Mutex<Vec<Option<T>>>);
+
+impl<T> Default for InnerGraphCache<T> {
+    fn default() -> Self {
+        Self(Mutex::new(Vec::new()))
+    }
+}
+
+impl<T> InnerGraphCache<T>
+where
+    T: Clone,
+{
+    /// Returns the data cached for an inner graph, computing and storing it first if needed.
+    ///
+    /// The lock is released during the computation, so that other inner graphs can be handled in the meantime.
+    fn get_or_compute<Ty, F>(&self, g: &InnerGraph<Ty>, compute: F) -> T
+    where
+        Ty: EdgeType,
+        F: FnOnce() -> T,
+    {
+        let mut cache_handle = self.0.lock().unwrap();
+        if cache_handle.len() <= g.index() {
+            cache_handle.resize(1 + g.index(), None);
+        } else if let Some(v) = &cache_handle[g.index()] {
+            return v.clone();
+        }
+        std::mem::drop(cache_handle);
+        let v = compute();
+        self.0.lock().unwrap()[g.index()] = Some(v.clone());
+        v
+    }
+}
+
+/// The kind of degree used by linkers to select nodes.
+#[derive(Clone, Copy)]
+enum DegreeKind {
+    /// The number of incoming edges
+    In,
+    /// The number of outgoing edges
+    Out,
+    /// The number of incident edges
+    Total,
+}
+
+impl FromStr for DegreeKind {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "in" => Ok(DegreeKind::In),
+            "out" => Ok(DegreeKind::Out),
+            "total" => Ok(DegreeKind::Total),
+            _ => Err(anyhow!(
+                r#"unknown degree "{}" (expected "in", "out" or "total")"#,
+                s
+            )),
+        }
+    }
+}
+
+/// Computes the degrees of the nodes of a graph.
+///
+/// For undirected graphs, all the kinds of degrees are the number of incident edges.
+fn degrees<Ty>(g: &Graph<Ty>, degree_kind: DegreeKind) -> Vec<usize>
+where
+    Ty: EdgeType,
+{
+    let (count_sources, count_targets) = match degree_kind {
+        _ if !Ty::is_directed() => (true, true),
+        DegreeKind::In => (false, true),
+        DegreeKind::Out => (true, false),
+        DegreeKind::Total => (true, true),
+    };
+    let mut degrees = vec![0; g.n_nodes()];
+    g.iter_edges().for_each(|(i, j)| {
+        if count_sources {
+            degrees[i] += 1;
+        }
+        if count_targets {
+            degrees[j] += 1;
+        }
+    });
+    degrees
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,204 @@
+use super::{BoxedLinker, DegreeKind, InnerGraphCache, Linker};
+use crate::{core::InnerGraph, InterGraphEdge, NamedParam, ParameterType, ParameterValue};
+use anyhow::{Context, Result};
+use petgraph::EdgeType;
+use rand::{distributions::WeightedIndex, prelude::Distribution, Rng};
+use std::{collections::HashSet, sync::Arc};
+
+/// The weighted distribution of the nodes of an inner graph.
+struct NodeWeights {
+    distribution: WeightedIndex<usize>,
+    /// The number of nodes that may be drawn, i.e. the ones with a positive weight
+    n_candidates: usize,
+}
+
+/// A linker that connects the graphs by edges which endpoints are chosen with a probability proportional to their degree.
+///
+/// The degree may be the number of incoming edges, the number of outgoing edges, or their sum.
+/// For undirected graphs, the three of them are the number of incident edges.
+/// In case all the nodes of a graph have a null degree, its nodes are chosen uniformly.
+///
+/// A fixed number of edges is drawn, from the first graph to the second one. Duplicate draws are ignored,
+/// so the graphs may be linked by fewer edges than requested, especially when a few hubs concentrate the weights.
+/// The draws stop as soon as all the pairs of nodes with a positive weight are linked.
+///
+/// The weights of the nodes of each inner graph are computed once, and then cached using the index of the inner graph.
+///
+/// Such linker can be created by passing `preferential/degree,k` to [`linkers::linker_from_str`](crate::linkers#linker_from_str) where
+///   - `degree` is the kind of degree to use, among `in`, `out` and `total`;
+///   - `k` is the number of edges to draw.
+#[derive(Default)]
+pub struct PreferentialLinker;
+
+impl<Ty, R> NamedParam<BoxedLinker<Ty, R>> for PreferentialLinker
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "preferential"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "Links the graphs by edges which endpoints are chosen with a probability proportional to their degree.",
+            r#"The first parameter is the kind of degree ("in", "out" or "total"), and the second one is the number of edges to draw."#,
+            "Duplicate draws are ignored.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![ParameterType::String, ParameterType::PositiveInteger]
+    }
+
+    fn try_with_params(&self, parameter_values: Vec<ParameterValue>) -> Result<BoxedLinker<Ty, R>> {
+        let degree_kind = parameter_values[0]
+            .unwrap_str()
+            .parse::<DegreeKind>()
+            .context("while building a preferential linker")?;
+        let k = parameter_values[1].unwrap_usize();
+        let cache = InnerGraphCache::default();
+        Ok(Box::new(move |g1, g2, rng| {
+            let (w1, w2) = match (
+                weights(&g1, degree_kind, &cache),
+                weights(&g2, degree_kind, &cache),
+            ) {
+                (Some(w1), Some(w2)) => (w1, w2),
+                _ => return vec![],
+            };
+            // k may be much higher than the number of pairs of nodes
+            let n_pairs = w1.n_candidates.saturating_mul(w2.n_candidates);
+            let mut drawn = HashSet::with_capacity(k.min(n_pairs));
+            let mut edges = Vec::with_capacity(k.min(n_pairs));
+            for _ in 0..k {
+                let (i, j) = (w1.distribution.sample(rng), w2.distribution.sample(rng));
+                if drawn.insert((i, j)) {
+                    edges.push(InterGraphEdge::FirstToSecond(i, j));
+                    if edges.len() == n_pairs {
+                        break;
+                    }
+                }
+            }
+            edges
+        }))
+    }
+}
+
+impl<Ty, R> Linker<Ty, R> for PreferentialLinker
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+/// Returns the weighted distribution of the nodes of an inner graph, or `None` if the graph has no nodes.
+fn weights<Ty>(
+    g: &InnerGraph<Ty>,
+    degree_kind: DegreeKind,
+    cache: &InnerGraphCache<Arc<NodeWeights>>,
+) -> Option<Arc<NodeWeights>>
+where
+    Ty: EdgeType,
+{
+    if g.graph().n_nodes() == 0 {
+        return None;
+    }
+    Some(cache.get_or_compute(g, || {
+        let degrees = super::degrees(g.graph(), degree_kind);
+        Arc::new(match WeightedIndex::new(&degrees) {
+            Ok(distribution) => NodeWeights {
+                distribution,
+                n_candidates: degrees.iter().filter(|d| **d > 0).count(),
+            },
+            Err(_) => NodeWeights {
+                distribution: WeightedIndex::new(vec![1; degrees.len()]).unwrap(),
+                n_candidates: degrees.len(),
+            },
+        })
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Graph;
+    use petgraph::{Directed, Undirected};
+    use rand::SeedableRng;
+    use rand_pcg::Pcg32;
+
+    fn star<Ty>(n: usize) -> Graph<Ty>
+    where
+        Ty: EdgeType,
+    {
+        let mut g = Graph::default();
+        (1..n).for_each(|i| g.new_edge(i, 0));
+        g
+    }
+
+    fn link<Ty>(degree: &str, k: usize, g0: &Graph<Ty>, g1: &Graph<Ty>) -> Vec<InterGraphEdge>
+    where
+        Ty: EdgeType,
+    {
+        let linker: BoxedLinker<Ty, Pcg32> = PreferentialLinker
+            .try_with_params(vec![
+                ParameterValue::String(degree.to_string()),
+                ParameterValue::PositiveInteger(k),
+            ])
+            .unwrap();
+        linker((0, g0).into(), (1, g1).into(), &mut Pcg32::seed_from_u64(0))
+    }
+
+    #[test]
+    fn test_in_degree() {
+        let g = star::<Directed>(10);
+        assert_eq!(
+            vec![InterGraphEdge::FirstToSecond(0, 0)],
+            link("in", 5, &g, &g)
+        );
+    }
+
+    #[test]
+    fn test_out_degree() {
+        let g = star::<Directed>(10);
+        let edges = link("out", 20, &g, &g);
+        assert!(!edges.is_empty());
+        assert!(edges
+            .iter()
+            .all(|e| matches!(e, InterGraphEdge::FirstToSecond(i, j) if *i != 0 && *j != 0)));
+    }
+
+    #[test]
+    fn test_undirected_degree() {
+        let g = star::<Undirected>(3);
+        let edges = link("in", 100, &g, &g);
+        assert_eq!(9, edges.len());
+    }
+
+    #[test]
+    fn test_no_edges() {
+        let mut g0: Graph<Directed> = Graph::default();
+        g0.new_node();
+        g0.new_node();
+        assert_eq!(4, link("total", 100, &g0, &g0).len());
+        assert!(link("total", 100, &g0, &Graph::default()).is_empty());
+    }
+
+    #[test]
+    fn test_huge_k() {
+        let g = star::<Directed>(3);
+        assert_eq!(9, link("total", 1_000_000_000, &g, &g).len());
+        assert_eq!(
+            vec![InterGraphEdge::FirstToSecond(0, 0)],
+            link("in", usize::MAX, &g, &g)
+        );
+    }
+
+    #[test]
+    fn test_unknown_degree() {
+        assert!((PreferentialLinker.try_with_params(vec![
+            ParameterValue::String("foo".to_string()),
+            ParameterValue::PositiveInteger(1),
+        ]) as Result<BoxedLinker<Directed, Pcg32>>)
+            .is_err());
+    }
+}
@@ -0,0 +1,213 @@
+use super::{BoxedLinker, Linker};
+use crate::{InterGraphEdge, NamedParam, ParameterType, ParameterValue};
+use anyhow::Result;
+use petgraph::{Directed, EdgeType};
+use rand::{seq::index, Rng};
+
+/// A linker that connects the graphs by a fixed number of edges, chosen uniformly at random.
+///
+/// The edges go from the first graph to the second one, and are drawn without replacement among all the possible pairs of nodes.
+/// In case the number of requested edges exceeds the number of pairs, all the pairs are linked.
+/// Contrary to the [`RandomLinker`](crate::linkers::RandomLinker), the number of edges does not depend on the size of the graphs,
+/// and the time required to link two graphs does not depend on the number of pairs of nodes.
+///
+/// Such linker can be created by passing `random_count/k` to [`linkers::linker_from_str`](crate::linkers#linker_from_str),
+/// where `k` is the number of edges.
+#[derive(Default)]
+pub struct RandomCountLinker;
+
+impl<Ty, R> NamedParam<BoxedLinker<Ty, R>> for RandomCountLinker
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "random_count"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "Links the nodes from the first graph to the ones of the second graph by a fixed number of edges chosen uniformly at random.",
+            "The number of edges is given by the first parameter; if it exceeds the number of pairs of nodes, all the pairs are linked.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![ParameterType::PositiveInteger]
+    }
+
+    fn try_with_params(&self, parameter_values: Vec<ParameterValue>) -> Result<BoxedLinker<Ty, R>> {
+        try_with_params(parameter_values, false)
+    }
+}
+
+impl<Ty, R> Linker<Ty, R> for RandomCountLinker
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+}
+
+/// A bidirectional linker that connects the graphs by a fixed number of edges in each direction, chosen uniformly at random.
+///
+/// The edges of each direction are drawn without replacement among all the possible pairs of nodes, independently from the other direction.
+/// In case the number of requested edges exceeds the number of pairs, all the pairs are linked.
+///
+/// Such linker can be created by passing `random_count_bi/k` to [`linkers::linker_from_str`](crate::linkers#linker_from_str),
+/// where `k` is the number of edges in each direction.
+#[derive(Default)]
+pub struct BidirectionalRandomCountLinker;
+
+impl<R> NamedParam<BoxedLinker<Directed, R>> for BidirectionalRandomCountLinker
+where
+    R: Rng,
+{
+    fn name(&self) -> &'static str {
+        "random_count_bi"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "Links the nodes from the first graph to the ones of the second graph by a fixed number of edges chosen uniformly at random, and vice-versa.",
+            "The number of edges in each direction is given by the first parameter; if it exceeds the number of pairs of nodes, all the pairs are linked.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![ParameterType::PositiveInteger]
+    }
+
+    fn try_with_params(
+        &self,
+        parameter_values: Vec<ParameterValue>,
+    ) -> Result<BoxedLinker<Directed, R>> {
+        try_with_params(parameter_values, true)
+    }
+}
+
+impl<R> Linker<Directed, R> for BidirectionalRandomCountLinker where R: Rng {}
+
+fn try_with_params<Ty, R>(
+    parameter_values: Vec<ParameterValue>,
+    bidirectional: bool,
+) -> Result<BoxedLinker<Ty, R>>
+where
+    R: Rng,
+    Ty: EdgeType,
+{
+    let k = parameter_values[0].unwrap_usize();
+    Ok(Box::new(move |g1, g2, rng| {
+        let n1 = g1.graph().n_nodes();
+        let n2 = g2.graph().n_nodes();
+        let mut edges = sample_pairs(n1, n2, k, rng)
+            .into_iter()
+            .map(|(i, j)| InterGraphEdge::FirstToSecond(i, j))
+            .collect::<Vec<InterGraphEdge>>();
+        if bidirectional {
+            edges.extend(
+                sample_pairs(n2, n1, k, rng)
+                    .into_iter()
+                    .map(|(j, i)| InterGraphEdge::SecondToFirst(j, i)),
+            );
+        }
+        edges
+    }))
+}
+
+/// Draws (at most) `k` distinct pairs of nodes between two graphs, sorted in lexicographic order.
+fn sample_pairs<R>(n1: usize, n2: usize, k: usize, rng: &mut R) -> Vec<(usize, usize)>
+where
+    R: Rng,
+{
+    let n_pairs = n1.saturating_mul(n2);
+    let mut indices = index::sample(rng, n_pairs, k.min(n_pairs)).into_vec();
+    indices.sort_unstable();
+    indices.into_iter().map(|p| (p / n2, p % n2)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Graph;
+    use rand::SeedableRng;
+    use rand_pcg::Pcg32;
+    use std::collections::HashSet;
+
+    fn path(n: usize) -> Graph<Directed> {
+        let mut g = Graph::default();
+        (1..n).for_each(|i| g.new_edge(i - 1, i));
+        g
+    }
+
+    #[test]
+    fn test_random_count() {
+        let g0 = path(10);
+        let g1 = path(20);
+        let linker = RandomCountLinker
+            .try_with_params(vec![ParameterValue::PositiveInteger(15)])
+            .unwrap();
+        let mut rng = Pcg32::seed_from_u64(0);
+        for _ in 0..10 {
+            let edges = linker((0, &g0).into(), (1, &g1).into(), &mut rng);
+            assert_eq!(15, edges.len());
+            let distinct = edges
+                .iter()
+                .map(|e| match e {
+                    InterGraphEdge::FirstToSecond(i, j) => {
+                        assert!(*i < 10 && *j < 20);
+                        (*i, *j)
+                    }
+                    InterGraphEdge::SecondToFirst(_, _) => panic!(),
+                })
+                .collect::<HashSet<(usize, usize)>>();
+            assert_eq!(15, distinct.len());
+        }
+    }
+
+    #[test]
+    fn test_random_count_all_pairs() {
+        let g0 = path(2);
+        let g1 = path(2);
+        let linker = RandomCountLinker
+            .try_with_params(vec![ParameterValue::PositiveInteger(10)])
+            .unwrap();
+        assert_eq!(
+            vec![
+                InterGraphEdge::FirstToSecond(0, 0),
+                InterGraphEdge::FirstToSecond(0, 1),
+                InterGraphEdge::FirstToSecond(1, 0),
+                InterGraphEdge::FirstToSecond(1, 1),
+            ],
+            linker((0, &g0).into(), (1, &g1).into(), &mut rand::thread_rng())
+        );
+    }
+
+    #[test]
+    fn test_random_count_bi() {
+        let g0 = path(3);
+        let g1 = path(5);
+        let linker = BidirectionalRandomCountLinker
+            .try_with_params(vec![ParameterValue::PositiveInteger(4)])
+            .unwrap();
+        let edges = linker(
+            (0, &g0).into(),
+            (1, &g1).into(),
+            &mut Pcg32::seed_from_u64(0),
+        );
+        assert_eq!(8, edges.len());
+        let n_second_to_first = edges
+            .iter()
+            .filter(|e| match e {
+                InterGraphEdge::FirstToSecond(i, j) => {
+                    assert!(*i < 3 && *j < 5);
+                    false
+                }
+                InterGraphEdge::SecondToFirst(j, i) => {
+                    assert!(*j < 5 && *i < 3);
+                    true
+                }
+            })
+            .count();
+        assert_eq!(4, n_second_to_first);
+    }
+}
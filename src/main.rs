@@ -3,7 +3,8 @@ mod commands;
 use commands::{
     DisplayEnginesDirectedCommand, DisplayEnginesUndirectedCommand, GenerateDirectedCommand,
     GenerateUndirectedCommand, GeneratorsDirectedCommand, GeneratorsUndirectedCommand,
-    LinkersDirectedCommand, LinkersUndirectedCommand,
+    LinkersDirectedCommand, LinkersUndirectedCommand, ParsersDirectedCommand,
+    ParsersUndirectedCommand,
 };
 use crusti_app_helper::{AppHelper, Command};
 
@@ -26,6 +27,8 @@ pub(crate) fn create_app_helper() -> AppHelper<'static> {
         Box::new(LinkersUndirectedCommand::new()),
         Box::new(DisplayEnginesDirectedCommand::new()),
         Box::new(DisplayEnginesUndirectedCommand::new()),
+        Box::new(ParsersDirectedCommand::new()),
+        Box::new(ParsersUndirectedCommand::new()),
     ];
     for c in commands {
         app.add_command(c);
@@ -0,0 +1,118 @@
+use super::{column_of, position, BoxedParser, GraphBuilder, GraphParser};
+use crate::{NamedParam, NodeIndexType, ParameterType, ParameterValue};
+use anyhow::{anyhow, Context, Result};
+use petgraph::EdgeType;
+use std::{collections::HashMap, io::BufRead};
+
+#[derive(Default)]
+pub struct AspartixGraphParser;
+
+impl<Ty> NamedParam<BoxedParser<Ty>> for AspartixGraphParser
+where
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "apx"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "Read a graph written in the Aspartix format.",
+            r#"Each line is an "arg(a)." or an "att(a,b)." statement; lines beginning with "%" are comments."#,
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![]
+    }
+
+    fn try_with_params(&self, _parameter_values: Vec<ParameterValue>) -> Result<BoxedParser<Ty>> {
+        Ok(Box::new(|reader| {
+            let mut builder = GraphBuilder::new();
+            let mut labels: HashMap<String, NodeIndexType> = HashMap::new();
+            for (i, line) in BufRead::lines(reader).enumerate() {
+                let line = line.with_context(|| format!("while reading line {}", i + 1))?;
+                let trimmed = line.trim();
+                if trimmed.is_empty() || trimmed.starts_with('%') {
+                    continue;
+                }
+                let at = |word: &str| position(i + 1, column_of(&line, word));
+                let statement = |prefix: &str| {
+                    trimmed
+                        .strip_prefix(prefix)
+                        .and_then(|s| s.trim_start().strip_prefix('('))
+                        .and_then(|s| s.strip_suffix('.'))
+                        .and_then(|s| s.trim_end().strip_suffix(')'))
+                };
+                if let Some(label) = statement("arg") {
+                    let label = label.trim();
+                    if labels.contains_key(label) {
+                        return Err(anyhow!(r#"argument "{}" is defined twice"#, label))
+                            .with_context(|| at(label));
+                    }
+                    labels.insert(label.to_string(), builder.new_node());
+                } else if let Some(args) = statement("att") {
+                    let (from, to) = args
+                        .split_once(',')
+                        .ok_or_else(|| anyhow!("expected two arguments in an attack"))
+                        .with_context(|| at(args))?;
+                    let index_of = |label: &str| {
+                        let label = label.trim();
+                        labels
+                            .get(label)
+                            .copied()
+                            .ok_or_else(|| anyhow!(r#"undefined argument "{}""#, label))
+                            .with_context(|| at(label))
+                    };
+                    builder.new_edge(index_of(from)?, index_of(to)?);
+                } else {
+                    return Err(anyhow!(r#"unexpected statement "{}""#, trimmed))
+                        .with_context(|| at(trimmed));
+                }
+            }
+            Ok(builder.build())
+        }))
+    }
+}
+
+impl<Ty> GraphParser<Ty> for AspartixGraphParser where Ty: EdgeType {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Graph;
+    use petgraph::{Directed, Undirected};
+
+    fn parse<Ty>(s: &str) -> Result<Graph<Ty>>
+    where
+        Ty: EdgeType,
+    {
+        (AspartixGraphParser.try_with_params(vec![]).unwrap() as BoxedParser<Ty>)(&mut s.as_bytes())
+    }
+
+    #[test]
+    fn test_ok() {
+        let content =
+            "% a comment\narg(a).\narg(b).\n\narg(c).\natt(a,b).\natt(b, a).\natt(c,c).\n";
+        let g: Graph<Directed> = parse(content).unwrap();
+        assert_eq!(3, g.n_nodes());
+        assert_eq!(
+            vec![(0, 1), (1, 0), (2, 2)],
+            g.iter_edges().collect::<Vec<_>>()
+        );
+        let g: Graph<Undirected> = parse(content).unwrap();
+        assert_eq!(vec![(0, 1), (2, 2)], g.iter_edges().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_undefined_argument() {
+        let err = parse::<Directed>("arg(a).\natt(a, b).\n").err().unwrap();
+        assert_eq!("at line 2, column 8", format!("{}", err));
+    }
+
+    #[test]
+    fn test_unexpected_statement() {
+        let err = parse::<Directed>("arg(a).\n  foo(a).\n").err().unwrap();
+        assert_eq!("at line 2, column 3", format!("{}", err));
+    }
+}
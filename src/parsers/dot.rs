@@ -0,0 +1,436 @@
+use super::{position, BoxedParser, GraphBuilder, GraphParser};
+use crate::{NamedParam, NodeIndexType, ParameterType, ParameterValue};
+use anyhow::{anyhow, Context, Result};
+use petgraph::EdgeType;
+use std::{collections::HashMap, iter::Peekable, str::Chars};
+
+#[derive(Default)]
+pub struct DotGraphParser;
+
+impl<Ty> NamedParam<BoxedParser<Ty>> for DotGraphParser
+where
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "dot"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "Read a graph written in the Graphviz DOT format.",
+            "Node and edge statements are supported, while subgraphs are not; graph, node and edge attribute statements are ignored.",
+            "Node attributes written by generators (eg. communities) are read back.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![]
+    }
+
+    fn try_with_params(&self, _parameter_values: Vec<ParameterValue>) -> Result<BoxedParser<Ty>> {
+        Ok(Box::new(|reader| {
+            let mut content = String::new();
+            reader
+                .read_to_string(&mut content)
+                .context("while reading the input")?;
+            let tokens = tokenize(&content)?;
+            DotReader::new(tokens).read()
+        }))
+    }
+}
+
+impl<Ty> GraphParser<Ty> for DotGraphParser where Ty: EdgeType {}
+
+#[derive(Clone, Debug, PartialEq)]
+enum TokenKind {
+    /// An identifier, a numeral or a quoted string (without its quotes)
+    Id(String),
+    /// A punctuation symbol
+    Symbol(char),
+    /// An edge operator (`--` or `->`)
+    EdgeOp(&'static str),
+}
+
+#[derive(Debug)]
+struct Token {
+    kind: TokenKind,
+    line: usize,
+    column: usize,
+}
+
+/// An iterator over the characters of the input which keeps track of the current position.
+struct CharCursor<'a> {
+    chars: Peekable<Chars<'a>>,
+    line: usize,
+    column: usize,
+}
+
+impl CharCursor<'_> {
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.chars.next();
+        if c == Some('\n') {
+            self.line += 1;
+            self.column = 1;
+        } else if c.is_some() {
+            self.column += 1;
+        }
+        c
+    }
+
+    fn skip_line(&mut self) {
+        while !matches!(self.next(), Some('\n') | None) {}
+    }
+}
+
+fn is_id_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '.' || !c.is_ascii()
+}
+
+fn tokenize(content: &str) -> Result<Vec<Token>> {
+    let mut cursor = CharCursor {
+        chars: content.chars().peekable(),
+        line: 1,
+        column: 1,
+    };
+    let mut tokens = vec![];
+    let mut at_line_start = true;
+    while let Some(c) = cursor.peek() {
+        let (line, column) = (cursor.line, cursor.column);
+        let at = || position(line, column);
+        if c == '\n' {
+            cursor.next();
+            at_line_start = true;
+            continue;
+        }
+        if c.is_whitespace() {
+            cursor.next();
+            continue;
+        }
+        if c == '#' && at_line_start {
+            cursor.skip_line();
+            continue;
+        }
+        at_line_start = false;
+        cursor.next();
+        let kind = match c {
+            '/' => match cursor.next() {
+                Some('/') => {
+                    cursor.skip_line();
+                    at_line_start = true;
+                    continue;
+                }
+                Some('*') => {
+                    let mut previous = None;
+                    loop {
+                        match cursor.next() {
+                            Some('/') if previous == Some('*') => break,
+                            None => return Err(anyhow!("unterminated comment")).with_context(at),
+                            current => previous = current,
+                        }
+                    }
+                    continue;
+                }
+                _ => return Err(anyhow!("unexpected character '/'")).with_context(at),
+            },
+            '"' => {
+                let mut s = String::new();
+                loop {
+                    match cursor.next() {
+                        Some('"') => break,
+                        Some('\\') => match cursor.next() {
+                            Some('"') => s.push('"'),
+                            Some('\n') => {}
+                            Some(other) => {
+                                s.push('\\');
+                                s.push(other);
+                            }
+                            None => return Err(anyhow!("unterminated string")).with_context(at),
+                        },
+                        Some(other) => s.push(other),
+                        None => return Err(anyhow!("unterminated string")).with_context(at),
+                    }
+                }
+                TokenKind::Id(s)
+            }
+            '-' if cursor.peek() == Some('-') => {
+                cursor.next();
+                TokenKind::EdgeOp("--")
+            }
+            '-' if cursor.peek() == Some('>') => {
+                cursor.next();
+                TokenKind::EdgeOp("->")
+            }
+            '{' | '}' | '[' | ']' | '=' | ';' | ',' | ':' => TokenKind::Symbol(c),
+            '<' => {
+                return Err(anyhow!("HTML strings are not supported")).with_context(at);
+            }
+            _ if c == '-' || is_id_char(c) => {
+                let mut s = c.to_string();
+                while let Some(next) = cursor.peek().filter(|n| is_id_char(*n)) {
+                    s.push(next);
+                    cursor.next();
+                }
+                TokenKind::Id(s)
+            }
+            _ => return Err(anyhow!("unexpected character '{}'", c)).with_context(at),
+        };
+        tokens.push(Token { kind, line, column });
+    }
+    Ok(tokens)
+}
+
+/// A recursive descent parser for the subset of the DOT language made of node and edge statements.
+struct DotReader<Ty>
+where
+    Ty: EdgeType,
+{
+    tokens: Vec<Token>,
+    next: usize,
+    builder: GraphBuilder<Ty>,
+    node_indices: HashMap<String, NodeIndexType>,
+}
+
+impl<Ty> DotReader<Ty>
+where
+    Ty: EdgeType,
+{
+    fn new(tokens: Vec<Token>) -> Self {
+        Self {
+            tokens,
+            next: 0,
+            builder: GraphBuilder::new(),
+            node_indices: HashMap::new(),
+        }
+    }
+
+    fn peek(&self) -> Option<&TokenKind> {
+        self.tokens.get(self.next).map(|t| &t.kind)
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(TokenKind::Id(s)) if s.eq_ignore_ascii_case(keyword))
+    }
+
+    /// Returns the position of the next token, or the position of the last one in case the end of the input is reached.
+    fn position(&self) -> String {
+        match self.tokens.get(self.next).or_else(|| self.tokens.last()) {
+            Some(t) => position(t.line, t.column),
+            None => position(1, 1),
+        }
+    }
+
+    fn next_token(&mut self) -> Result<TokenKind> {
+        let token = self
+            .tokens
+            .get(self.next)
+            .map(|t| t.kind.clone())
+            .ok_or_else(|| anyhow!("unexpected end of input"))
+            .with_context(|| self.position())?;
+        self.next += 1;
+        Ok(token)
+    }
+
+    fn expect_symbol(&mut self, symbol: char) -> Result<()> {
+        let position = self.position();
+        match self.next_token()? {
+            TokenKind::Symbol(c) if c == symbol => Ok(()),
+            _ => Err(anyhow!("expected '{}'", symbol)).context(position),
+        }
+    }
+
+    fn expect_id(&mut self) -> Result<String> {
+        let position = self.position();
+        match self.next_token()? {
+            TokenKind::Id(s) => Ok(s),
+            _ => Err(anyhow!("expected an identifier")).context(position),
+        }
+    }
+
+    fn skip_symbol(&mut self, symbol: char) -> bool {
+        if self.peek() == Some(&TokenKind::Symbol(symbol)) {
+            self.next += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn read(mut self) -> Result<crate::Graph<Ty>> {
+        if self.peek_keyword("strict") {
+            self.next += 1;
+        }
+        let position = self.position();
+        let edge_op = match self.expect_id()? {
+            s if s.eq_ignore_ascii_case("graph") => "--",
+            s if s.eq_ignore_ascii_case("digraph") => "->",
+            _ => return Err(anyhow!(r#"expected "graph" or "digraph""#)).context(position),
+        };
+        if matches!(self.peek(), Some(TokenKind::Id(_))) {
+            self.next += 1;
+        }
+        self.expect_symbol('{')?;
+        while !self.skip_symbol('}') {
+            self.read_statement(edge_op)?;
+            self.skip_symbol(';');
+        }
+        if self.next < self.tokens.len() {
+            return Err(anyhow!("unexpected content after the end of the graph"))
+                .context(self.position());
+        }
+        Ok(self.builder.build())
+    }
+
+    fn read_statement(&mut self, edge_op: &str) -> Result<()> {
+        let position = self.position();
+        if self.peek_keyword("subgraph") || self.peek() == Some(&TokenKind::Symbol('{')) {
+            return Err(anyhow!("subgraphs are not supported")).context(position);
+        }
+        if self.peek_keyword("graph") || self.peek_keyword("node") || self.peek_keyword("edge") {
+            self.next += 1;
+            self.read_attributes()?;
+            return Ok(());
+        }
+        let id = self.expect_id()?;
+        if self.skip_symbol('=') {
+            self.expect_id()?;
+            return Ok(());
+        }
+        let mut nodes = vec![self.read_node_id(id)?];
+        while let Some(TokenKind::EdgeOp(op)) = self.peek() {
+            if *op != edge_op {
+                return Err(anyhow!(r#"unexpected edge operator "{}""#, op))
+                    .context(self.position());
+            }
+            self.next += 1;
+            let position = self.position();
+            if self.peek_keyword("subgraph") || self.peek() == Some(&TokenKind::Symbol('{')) {
+                return Err(anyhow!("subgraphs are not supported")).context(position);
+            }
+            let id = self.expect_id()?;
+            nodes.push(self.read_node_id(id)?);
+        }
+        let attributes = self.read_attributes()?;
+        if nodes.len() == 1 {
+            for (name, value, position) in attributes {
+                self.builder
+                    .set_node_attribute(nodes[0], &name, &value)
+                    .context(position)?;
+            }
+        } else {
+            nodes
+                .windows(2)
+                .for_each(|pair| self.builder.new_edge(pair[0], pair[1]));
+        }
+        Ok(())
+    }
+
+    /// Returns the index of a node given its identifier, skipping the port that may follow it.
+    fn read_node_id(&mut self, id: String) -> Result<NodeIndexType> {
+        for _ in 0..2 {
+            if self.skip_symbol(':') {
+                self.expect_id()?;
+            }
+        }
+        let builder = &mut self.builder;
+        Ok(*self
+            .node_indices
+            .entry(id)
+            .or_insert_with(|| builder.new_node()))
+    }
+
+    /// Reads a sequence of attribute lists, returning the names, the values and the positions of the attributes.
+    fn read_attributes(&mut self) -> Result<Vec<(String, String, String)>> {
+        let mut attributes = vec![];
+        while self.skip_symbol('[') {
+            while !self.skip_symbol(']') {
+                let position = self.position();
+                let name = self.expect_id()?;
+                self.expect_symbol('=')?;
+                let value = self.expect_id()?;
+                attributes.push((name, value, position));
+                if !self.skip_symbol(';') {
+                    self.skip_symbol(',');
+                }
+            }
+        }
+        Ok(attributes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generators::COMMUNITY_ATTRIBUTE, Graph, NodeAttributeValue};
+    use petgraph::{Directed, Undirected};
+
+    fn parse<Ty>(s: &str) -> Result<Graph<Ty>>
+    where
+        Ty: EdgeType,
+    {
+        (DotGraphParser.try_with_params(vec![]).unwrap() as BoxedParser<Ty>)(&mut s.as_bytes())
+    }
+
+    #[test]
+    fn test_written_by_display() {
+        let content = r#"graph {
+    0 [ label = "0" community = "0" ]
+    1 [ label = "1" community = "1" ]
+    2 [ label = "2" community = "1" ]
+    0 -- 1 [ ]
+    0 -- 2 [ ]
+}
+"#;
+        let g: Graph<Undirected> = parse(content).unwrap();
+        assert_eq!(3, g.n_nodes());
+        assert_eq!(vec![(0, 1), (0, 2)], g.iter_edges().collect::<Vec<_>>());
+        assert_eq!(
+            Some(&NodeAttributeValue::PositiveInteger(1)),
+            g.node_attributes(2).get(COMMUNITY_ATTRIBUTE)
+        );
+    }
+
+    #[test]
+    fn test_general_syntax() {
+        let content = r#"/* a comment */
+strict digraph "G" {
+    rankdir = LR; // another comment
+    node [shape=box];
+    a -> b -> "c d":port -> a;
+    b -> a [color="red", style=bold]
+}"#;
+        let g: Graph<Directed> = parse(content).unwrap();
+        assert_eq!(3, g.n_nodes());
+        assert_eq!(
+            vec![(0, 1), (1, 2), (2, 0), (1, 0)],
+            g.iter_edges().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_wrong_edge_operator() {
+        let err = parse::<Directed>("digraph {\n  a -> b\n  b -- c\n}")
+            .err()
+            .unwrap();
+        assert_eq!("at line 3, column 5", format!("{}", err));
+    }
+
+    #[test]
+    fn test_subgraph() {
+        let err = parse::<Directed>("digraph {\n  subgraph { a }\n}")
+            .err()
+            .unwrap();
+        assert_eq!("at line 2, column 3", format!("{}", err));
+    }
+
+    #[test]
+    fn test_wrong_attribute_value() {
+        let err = parse::<Directed>("digraph {\n  a [community=x]\n}")
+            .err()
+            .unwrap();
+        assert_eq!("at line 2, column 6", format!("{}", err));
+    }
+}
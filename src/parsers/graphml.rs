@@ -0,0 +1,238 @@
+use super::{position, BoxedParser, GraphBuilder, GraphParser};
+use crate::{NamedParam, NodeIndexType, ParameterType, ParameterValue};
+use anyhow::{anyhow, Context, Result};
+use petgraph::EdgeType;
+use std::collections::HashMap;
+use xml::{
+    attribute::OwnedAttribute,
+    common::{Position, TextPosition},
+    reader::XmlEvent,
+    EventReader,
+};
+
+#[derive(Default)]
+pub struct GraphMLGraphParser;
+
+impl<Ty> NamedParam<BoxedParser<Ty>> for GraphMLGraphParser
+where
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "graphml"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "Read a graph written in the GraphML format.",
+            "Nested graphs and hyperedges are not supported.",
+            "Node attributes written by generators (eg. communities) are read back from GraphML data.",
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![]
+    }
+
+    fn try_with_params(&self, _parameter_values: Vec<ParameterValue>) -> Result<BoxedParser<Ty>> {
+        Ok(Box::new(|reader| {
+            let content = read_content(reader)?;
+            let mut builder = GraphBuilder::new();
+            let mut node_indices = HashMap::with_capacity(content.nodes.len());
+            for (id, pos) in content.nodes.iter() {
+                if node_indices
+                    .insert(id.as_str(), builder.new_node())
+                    .is_some()
+                {
+                    return Err(anyhow!(r#"node "{}" is defined twice"#, id)).context(at(pos));
+                }
+            }
+            for (node, key, value, pos) in content.node_data.iter() {
+                let name = content.keys.get(key).unwrap_or(key);
+                builder
+                    .set_node_attribute(*node, name, value.trim())
+                    .context(at(pos))?;
+            }
+            for (source, target, pos) in content.edges.iter() {
+                let index_of = |id: &String| -> Result<NodeIndexType> {
+                    node_indices
+                        .get(id.as_str())
+                        .copied()
+                        .ok_or_else(|| anyhow!(r#"undefined node "{}""#, id))
+                        .context(at(pos))
+                };
+                builder.new_edge(index_of(source)?, index_of(target)?);
+            }
+            Ok(builder.build())
+        }))
+    }
+}
+
+impl<Ty> GraphParser<Ty> for GraphMLGraphParser where Ty: EdgeType {}
+
+/// Formats a position given by the XML reader, which counts lines and columns from 0.
+fn at(pos: &TextPosition) -> String {
+    position(pos.row as usize + 1, pos.column as usize + 1)
+}
+
+/// The relevant content of a GraphML document, as read before node identifiers and keys are resolved.
+#[derive(Default)]
+struct GraphMLContent {
+    /// The node identifiers, in the order of their definitions
+    nodes: Vec<(String, TextPosition)>,
+    /// The node data, given by the index of the node, the key and the value
+    node_data: Vec<(NodeIndexType, String, String, TextPosition)>,
+    /// The edges, given by the identifiers of their source and target
+    edges: Vec<(String, String, TextPosition)>,
+    /// The attribute names associated with the keys
+    keys: HashMap<String, String>,
+}
+
+fn read_content(reader: &mut dyn std::io::BufRead) -> Result<GraphMLContent> {
+    let mut content = GraphMLContent::default();
+    let mut parser = EventReader::new(reader);
+    let mut graph_depth = 0;
+    let mut in_node = false;
+    let mut current_data: Option<(String, String, TextPosition)> = None;
+    loop {
+        let event = parser
+            .next()
+            .context("while reading the GraphML document")?;
+        let pos = parser.position();
+        match event {
+            XmlEvent::StartElement {
+                name, attributes, ..
+            } => match name.local_name.as_str() {
+                "graph" => {
+                    if graph_depth > 0 {
+                        return Err(anyhow!("nested graphs are not supported")).context(at(&pos));
+                    }
+                    graph_depth += 1;
+                }
+                "node" => {
+                    let id = required_attribute(&attributes, "id", &pos)?;
+                    content.nodes.push((id, pos));
+                    in_node = true;
+                }
+                "edge" => {
+                    let source = required_attribute(&attributes, "source", &pos)?;
+                    let target = required_attribute(&attributes, "target", &pos)?;
+                    content.edges.push((source, target, pos));
+                }
+                "hyperedge" => {
+                    return Err(anyhow!("hyperedges are not supported")).context(at(&pos));
+                }
+                "key" => {
+                    let id = required_attribute(&attributes, "id", &pos)?;
+                    if let Some(name) = attribute(&attributes, "attr.name") {
+                        content.keys.insert(id, name);
+                    }
+                }
+                "data" if in_node => {
+                    let key = required_attribute(&attributes, "key", &pos)?;
+                    current_data = Some((key, String::new(), pos));
+                }
+                _ => {}
+            },
+            XmlEvent::Characters(s) | XmlEvent::CData(s) => {
+                if let Some((_, value, _)) = current_data.as_mut() {
+                    value.push_str(&s);
+                }
+            }
+            XmlEvent::EndElement { name } => match name.local_name.as_str() {
+                "node" => in_node = false,
+                "data" => {
+                    if let Some((key, value, data_pos)) = current_data.take() {
+                        let node = content.nodes.len() - 1;
+                        content.node_data.push((node, key, value, data_pos));
+                    }
+                }
+                _ => {}
+            },
+            XmlEvent::EndDocument => break,
+            _ => {}
+        }
+    }
+    if graph_depth == 0 {
+        return Err(anyhow!("missing graph element"));
+    }
+    Ok(content)
+}
+
+fn attribute(attributes: &[OwnedAttribute], name: &str) -> Option<String> {
+    attributes
+        .iter()
+        .find(|a| a.name.local_name == name)
+        .map(|a| a.value.clone())
+}
+
+fn required_attribute(
+    attributes: &[OwnedAttribute],
+    name: &str,
+    pos: &TextPosition,
+) -> Result<String> {
+    attribute(attributes, name)
+        .ok_or_else(|| anyhow!(r#"missing attribute "{}""#, name))
+        .context(at(pos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generators::POSITION_ATTRIBUTE, Graph, NodeAttributeValue};
+    use petgraph::{Directed, Undirected};
+
+    fn parse<Ty>(s: &str) -> Result<Graph<Ty>>
+    where
+        Ty: EdgeType,
+    {
+        (GraphMLGraphParser.try_with_params(vec![]).unwrap() as BoxedParser<Ty>)(&mut s.as_bytes())
+    }
+
+    #[test]
+    fn test_written_by_display() {
+        let content = r#"<?xml version="1.0" encoding="UTF-8"?>
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+  <graph edgedefault="undirected">
+    <node id="n0">
+      <data key="pos">0.4,0.6</data>
+    </node>
+    <node id="n1">
+      <data key="pos">0.5,0.5</data>
+    </node>
+    <node id="n2">
+      <data key="pos">0.1,0</data>
+    </node>
+    <edge id="e0" source="n0" target="n1" />
+    <edge id="e1" source="n2" target="n1" />
+    <edge id="e2" source="n1" target="n2" />
+  </graph>
+  <key id="pos" for="node" attr.name="pos" attr.type="string" />
+</graphml>
+"#;
+        let g: Graph<Undirected> = parse(content).unwrap();
+        assert_eq!(3, g.n_nodes());
+        assert_eq!(vec![(0, 1), (2, 1)], g.iter_edges().collect::<Vec<_>>());
+        assert_eq!(
+            Some(&NodeAttributeValue::FloatList(vec![0.4, 0.6])),
+            g.node_attributes(0).get(POSITION_ATTRIBUTE)
+        );
+    }
+
+    #[test]
+    fn test_undefined_node() {
+        let content = r#"<graphml>
+  <graph edgedefault="directed">
+    <node id="n0"/>
+    <edge source="n0" target="n1"/>
+  </graph>
+</graphml>"#;
+        let err = parse::<Directed>(content).err().unwrap();
+        assert_eq!("at line 4, column 5", format!("{}", err));
+    }
+
+    #[test]
+    fn test_missing_graph() {
+        assert!(parse::<Directed>("<graphml></graphml>").is_err());
+        assert!(parse::<Directed>("<graphml>").is_err());
+    }
+}
@@ -0,0 +1,120 @@
+use super::{column_of, position, BoxedParser, GraphBuilder, GraphParser};
+use crate::{NamedParam, NodeIndexType, ParameterType, ParameterValue};
+use anyhow::{anyhow, Context, Result};
+use petgraph::EdgeType;
+use std::io::BufRead;
+
+#[derive(Default)]
+pub struct ICCMADimacsGraphParser;
+
+impl<Ty> NamedParam<BoxedParser<Ty>> for ICCMADimacsGraphParser
+where
+    Ty: EdgeType,
+{
+    fn name(&self) -> &'static str {
+        "iccma_dimacs"
+    }
+
+    fn description(&self) -> Vec<&'static str> {
+        vec![
+            "Read a graph written in the DIMACS-like format used at ICCMA'23.",
+            r##"A "p af n" header is followed by edges between nodes labelled from 1 to n; lines beginning with "#" are comments."##,
+        ]
+    }
+
+    fn expected_parameter_types(&self) -> Vec<ParameterType> {
+        vec![]
+    }
+
+    fn try_with_params(&self, _parameter_values: Vec<ParameterValue>) -> Result<BoxedParser<Ty>> {
+        Ok(Box::new(|reader| {
+            let mut builder = GraphBuilder::new();
+            let mut n_nodes = None;
+            for (i, line) in BufRead::lines(reader).enumerate() {
+                let line = line.with_context(|| format!("while reading line {}", i + 1))?;
+                let trimmed = line.trim();
+                if trimmed.is_empty() || trimmed.starts_with('#') {
+                    continue;
+                }
+                let at = |word: &str| position(i + 1, column_of(&line, word));
+                let words = trimmed.split_whitespace().collect::<Vec<&str>>();
+                match n_nodes {
+                    None => {
+                        if words.len() != 3 || words[0] != "p" || words[1] != "af" {
+                            return Err(anyhow!(r#"expected a "p af n" header"#))
+                                .with_context(|| at(trimmed));
+                        }
+                        let n = str::parse::<usize>(words[2])
+                            .context("while parsing the number of arguments")
+                            .with_context(|| at(words[2]))?;
+                        (0..n).for_each(|_| {
+                            builder.new_node();
+                        });
+                        n_nodes = Some(n);
+                    }
+                    Some(n) => {
+                        if words.len() != 2 {
+                            return Err(anyhow!("expected two arguments in an attack"))
+                                .with_context(|| at(trimmed));
+                        }
+                        let index_of = |w: &str| -> Result<NodeIndexType> {
+                            match str::parse::<usize>(w) {
+                                Ok(a) if a >= 1 && a <= n => Ok(a - 1),
+                                _ => Err(anyhow!(r#"invalid argument "{}""#, w))
+                                    .with_context(|| at(w)),
+                            }
+                        };
+                        builder.new_edge(index_of(words[0])?, index_of(words[1])?);
+                    }
+                }
+            }
+            if n_nodes.is_none() {
+                return Err(anyhow!(r#"missing "p af n" header"#));
+            }
+            Ok(builder.build())
+        }))
+    }
+}
+
+impl<Ty> GraphParser<Ty> for ICCMADimacsGraphParser where Ty: EdgeType {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Graph;
+    use petgraph::{Directed, Undirected};
+
+    fn parse<Ty>(s: &str) -> Result<Graph<Ty>>
+    where
+        Ty: EdgeType,
+    {
+        (ICCMADimacsGraphParser.try_with_params(vec![]).unwrap() as BoxedParser<Ty>)(
+            &mut s.as_bytes(),
+        )
+    }
+
+    #[test]
+    fn test_ok() {
+        let g: Graph<Directed> = parse("# a comment\np af 4\n1 2\n2 3\n").unwrap();
+        assert_eq!(4, g.n_nodes());
+        assert_eq!(vec![(0, 1), (1, 2)], g.iter_edges().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_reverse_edges_undirected() {
+        let g: Graph<Undirected> = parse("p af 2\n1 2\n2 1\n").unwrap();
+        assert_eq!(vec![(0, 1)], g.iter_edges().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        let err = parse::<Directed>("p af 2\n1  3\n").err().unwrap();
+        assert_eq!("at line 2, column 4", format!("{}", err));
+    }
+
+    #[test]
+    fn test_missing_header() {
+        assert!(parse::<Directed>("1 2\n").is_err());
+        assert!(parse::<Directed>("").is_err());
+    }
+}
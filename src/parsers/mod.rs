@@ -0,0 +1,235 @@
+//! A module dedicated to the formats used to read graphs.
+//!
+//! The formats that can be read are the ones the [display engines](crate::display) write,
+//! so graphs produced by this crate (or benchmark sets using the same formats) can be read back, converted and linked again.
+//!
+//! Parsers are closures that take a [`BufRead`] and return the graph it contains.
+//! Errors are reported with the line and the column they occur at.
+//!
+//! Nodes are labelled by the order of their first appearance in the input.
+//! Duplicate edges are ignored; when reading an undirected graph, an edge and its reverse are considered as the same edge.
+//! The node attributes written by the generators of this crate (`community`, `pos` and `side`) are read back when the format supports them;
+//! other attributes are ignored.
+//!
+//! ```
+//! # use crusti_g2io::parsers;
+//! use petgraph::Directed;
+//!
+//! let parser = parsers::directed_parser_from_str("apx").unwrap();
+//! let graph = parser(&mut "arg(a).\narg(b).\natt(a,b).\n".as_bytes()).unwrap();
+//! assert_eq!(2, graph.n_nodes());
+//! assert_eq!(1, graph.n_edges());
+//! ```
+//!
+//! # Setting up a new parser
+//!
+//! Setting up a new parser is pretty similar to [setting up a new display engine](crate::display).
+//!
+//! The files are located in `src/parsers`, the empty trait to "implement" is [`GraphParser`],
+//! and the collections of parsers in `src/parsers/mod.rs` are `PARSERS_UNDIRECTED` and `PARSERS_DIRECTED`.
+//! In case the format has a usual file extension, it should also be added to `EXTENSIONS`.
+//! The closure returned by the `try_with_params` function takes a [`BufRead`] and returns the graph it contains.
+
+mod aspartix;
+use aspartix::AspartixGraphParser;
+
+mod dot;
+use dot::DotGraphParser;
+
+mod graphml;
+use graphml::GraphMLGraphParser;
+
+mod iccma_dimacs;
+use iccma_dimacs::ICCMADimacsGraphParser;
+
+use crate::{
+    core::named_param,
+    generators::{COMMUNITY_ATTRIBUTE, POSITION_ATTRIBUTE, SIDE_ATTRIBUTE},
+    Graph, NamedParam, NodeAttributeValue, NodeIndexType,
+};
+use anyhow::{anyhow, Context, Result};
+use lazy_static::lazy_static;
+use petgraph::{Directed, EdgeType, Undirected};
+use std::{collections::HashSet, io::BufRead};
+
+/// A boxed function that takes a reader and parses the graph it contains.
+/// The expected format depends on the implementation of the parser.
+pub type BoxedParser<Ty> = Box<dyn Fn(&mut dyn BufRead) -> Result<Graph<Ty>>>;
+
+/// A trait for objects that are used to read graphs.
+pub trait GraphParser<Ty>: NamedParam<BoxedParser<Ty>>
+where
+    Ty: EdgeType,
+{
+}
+
+lazy_static! {
+    pub(crate) static ref PARSERS_DIRECTED: [Box<dyn GraphParser<Directed> + Sync>; 4] = [
+        Box::new(AspartixGraphParser),
+        Box::new(DotGraphParser),
+        Box::new(GraphMLGraphParser),
+        Box::new(ICCMADimacsGraphParser)
+    ];
+}
+
+lazy_static! {
+    pub(crate) static ref PARSERS_UNDIRECTED: [Box<dyn GraphParser<Undirected> + Sync>; 4] = [
+        Box::new(AspartixGraphParser),
+        Box::new(DotGraphParser),
+        Box::new(GraphMLGraphParser),
+        Box::new(ICCMADimacsGraphParser)
+    ];
+}
+
+/// The usual file extensions of the formats, and the names of the related parsers.
+const EXTENSIONS: [(&str, &str); 5] = [
+    ("apx", "apx"),
+    ("af", "iccma_dimacs"),
+    ("dot", "dot"),
+    ("gv", "dot"),
+    ("graphml", "graphml"),
+];
+
+/// Iterates over all the parsers for directed graphs.
+pub fn iter_directed_parsers(
+) -> impl Iterator<Item = &'static (dyn GraphParser<Directed> + Sync + 'static)> + 'static {
+    PARSERS_DIRECTED.iter().map(|b| b.as_ref())
+}
+
+/// Iterates over all the parsers for undirected graphs.
+pub fn iter_undirected_parsers(
+) -> impl Iterator<Item = &'static (dyn GraphParser<Undirected> + Sync + 'static)> + 'static {
+    PARSERS_UNDIRECTED.iter().map(|b| b.as_ref())
+}
+
+/// Given a string representing a parser for directed graphs, returns the corresponding object.
+pub fn directed_parser_from_str(s: &str) -> Result<BoxedParser<Directed>> {
+    named_param::named_from_str(PARSERS_DIRECTED.as_slice(), s)
+        .context("while building a parser from a string")
+}
+
+/// Given a string representing a parser for undirected graphs, returns the corresponding object.
+pub fn undirected_parser_from_str(s: &str) -> Result<BoxedParser<Undirected>> {
+    named_param::named_from_str(PARSERS_UNDIRECTED.as_slice(), s)
+        .context("while building a parser from a string")
+}
+
+/// Returns `true` if a parser is associated with the given file extension.
+pub(crate) fn is_supported_extension(extension: &str) -> bool {
+    EXTENSIONS.iter().any(|(ext, _)| *ext == extension)
+}
+
+/// Returns the parser associated with a file extension, for any kind of graph.
+pub(crate) fn parser_from_extension<Ty>(extension: &str) -> Result<BoxedParser<Ty>>
+where
+    Ty: EdgeType,
+{
+    let name = EXTENSIONS
+        .iter()
+        .find(|(ext, _)| *ext == extension)
+        .map(|(_, name)| *name)
+        .ok_or_else(|| {
+            anyhow!(
+                r#"unsupported file extension "{}" (expected one of {})"#,
+                extension,
+                EXTENSIONS
+                    .iter()
+                    .map(|(ext, _)| format!(".{}", ext))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )
+        })?;
+    match name {
+        "apx" => AspartixGraphParser.try_with_params(vec![]),
+        "dot" => DotGraphParser.try_with_params(vec![]),
+        "graphml" => GraphMLGraphParser.try_with_params(vec![]),
+        "iccma_dimacs" => ICCMADimacsGraphParser.try_with_params(vec![]),
+        _ => unreachable!(),
+    }
+}
+
+/// Formats a position in the input, both line and column counting from 1.
+fn position(line: usize, column: usize) -> String {
+    format!("at line {}, column {}", line, column)
+}
+
+/// Returns the column (counting from 1) at which a word begins in a line, given both as string slices.
+fn column_of(line: &str, word: &str) -> usize {
+    word.as_ptr() as usize - line.as_ptr() as usize + 1
+}
+
+/// A helper used by parsers to build graphs, ignoring duplicate edges.
+struct GraphBuilder<Ty>
+where
+    Ty: EdgeType,
+{
+    graph: Graph<Ty>,
+    edges: HashSet<(NodeIndexType, NodeIndexType)>,
+}
+
+impl<Ty> GraphBuilder<Ty>
+where
+    Ty: EdgeType,
+{
+    fn new() -> Self {
+        Self {
+            graph: Graph::default(),
+            edges: HashSet::new(),
+        }
+    }
+
+    fn new_node(&mut self) -> NodeIndexType {
+        self.graph.new_node();
+        self.graph.n_nodes() - 1
+    }
+
+    fn new_edge(&mut self, from: NodeIndexType, to: NodeIndexType) {
+        let key = if Ty::is_directed() {
+            (from, to)
+        } else {
+            (from.min(to), from.max(to))
+        };
+        if self.edges.insert(key) {
+            self.graph.new_edge(from, to);
+        }
+    }
+
+    /// Sets a node attribute from its name and its textual value, if it is one of the attributes written by generators.
+    fn set_node_attribute(&mut self, node: NodeIndexType, name: &str, value: &str) -> Result<()> {
+        let context = || {
+            format!(
+                r#"while reading the value "{}" of attribute "{}""#,
+                value, name
+            )
+        };
+        let attribute = match name {
+            COMMUNITY_ATTRIBUTE => Some((
+                COMMUNITY_ATTRIBUTE,
+                NodeAttributeValue::PositiveInteger(value.parse().with_context(context)?),
+            )),
+            SIDE_ATTRIBUTE => Some((
+                SIDE_ATTRIBUTE,
+                NodeAttributeValue::PositiveInteger(value.parse().with_context(context)?),
+            )),
+            POSITION_ATTRIBUTE => Some((
+                POSITION_ATTRIBUTE,
+                NodeAttributeValue::FloatList(
+                    value
+                        .split(',')
+                        .map(|x| x.trim().parse::<f64>())
+                        .collect::<Result<Vec<f64>, _>>()
+                        .with_context(context)?,
+                ),
+            )),
+            _ => None,
+        };
+        if let Some((name, value)) = attribute {
+            self.graph.set_node_attribute(node, name, value);
+        }
+        Ok(())
+    }
+
+    fn build(self) -> Graph<Ty> {
+        self.graph
+    }
+}